@@ -0,0 +1,106 @@
+use crate::i18n::t;
+
+// 命令行参数：第一个非选项参数为命令（watch / all / serve 等），全局选项可出现在任意位置，
+// 其余参数留给各个命令自行解析
+pub struct Options {
+    pub command: Option<String>,
+    pub verbosity: i32,
+    pub events: Option<EventFormat>,
+    // --lang 指定的输出语言，未指定时由 LANG 环境变量决定
+    pub lang: Option<String>,
+    pub args: Args,
+}
+
+// 机器可读事件流的格式，目前只支持 JSON lines
+pub enum EventFormat {
+    JsonLines,
+}
+
+pub fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        command: None,
+        verbosity: 0,
+        events: None,
+        lang: None,
+        args: Args(Vec::new()),
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--events" => {
+                let format = args.next().ok_or_else(|| t!("cli.events_requires_format"))?;
+                options.events = Some(parse_event_format(format)?);
+            }
+            flag if flag.starts_with("--events=") => {
+                options.events = Some(parse_event_format(&flag["--events=".len()..])?);
+            }
+            "--lang" => {
+                let lang = args.next().ok_or_else(|| t!("cli.requires_value", option = "--lang"))?;
+                options.lang = Some(lang.clone());
+            }
+            flag if flag.starts_with("--lang=") => options.lang = Some(flag["--lang=".len()..].to_string()),
+            "-v" | "--verbose" => options.verbosity += 1,
+            "-q" | "--quiet" => options.verbosity -= 1,
+            flag if flag.starts_with("-v") && flag[1..].chars().all(|c| c == 'v') => {
+                options.verbosity += flag.len() as i32 - 1;
+            }
+            flag if flag.starts_with("-q") && flag[1..].chars().all(|c| c == 'q') => {
+                options.verbosity -= flag.len() as i32 - 1;
+            }
+            command if options.command.is_none() && !command.starts_with('-') => {
+                options.command = Some(command.to_string());
+            }
+            other => options.args.0.push(other.to_string()),
+        }
+    }
+
+    Ok(options)
+}
+
+fn parse_event_format(format: &str) -> Result<EventFormat, String> {
+    match format {
+        "jsonl" => Ok(EventFormat::JsonLines),
+        other => Err(t!("cli.unsupported_event_format", format = other)),
+    }
+}
+
+// 命令自身的参数，按需取出；finish 检查是否还有无法识别的参数
+pub struct Args(Vec<String>);
+
+impl Args {
+    // 取出 "--name value" 或 "--name=value"
+    pub fn value(&mut self, name: &str) -> Result<Option<String>, String> {
+        let prefix = format!("{}=", name);
+        if let Some(index) = self.0.iter().position(|arg| arg.starts_with(&prefix)) {
+            return Ok(Some(self.0.remove(index)[prefix.len()..].to_string()));
+        }
+        match self.0.iter().position(|arg| arg == name) {
+            Some(index) if index + 1 < self.0.len() => {
+                self.0.remove(index);
+                Ok(Some(self.0.remove(index)))
+            }
+            Some(_) => Err(t!("cli.requires_value", option = name)),
+            None => Ok(None),
+        }
+    }
+
+    // 取出不带值的开关，如 "--sign"
+    pub fn flag(&mut self, name: &str) -> bool {
+        match self.0.iter().position(|arg| arg == name) {
+            Some(index) => {
+                self.0.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn finish(self) -> Result<(), String> {
+        match self.0.first() {
+            Some(arg) if arg.starts_with('-') => Err(t!("cli.unknown_option", option = arg)),
+            Some(arg) => Err(t!("cli.unexpected_argument", argument = arg)),
+            None => Ok(()),
+        }
+    }
+}
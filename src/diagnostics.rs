@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+// 编译器诊断信息，文件路径已转换为相对于仓库根目录的路径
+#[derive(Serialize, Debug, Clone)]
+pub struct Diagnostic {
+    pub level: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    pub spans: Vec<Span>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Span {
+    pub file: String,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub primary: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+// 单个测试用例的结果（解析自 libtest 的 "test xxx ... ok" 输出）
+#[derive(Serialize, Debug, Clone)]
+pub struct TestCase {
+    pub test: String,
+    pub outcome: String,
+}
+
+// 一个评测阶段的输出：还原后的可读文本、诊断信息和测试用例结果
+pub struct StageOutput {
+    pub text: String,
+    pub diagnostics: Vec<Diagnostic>,
+    pub tests: Vec<TestCase>,
+}
+
+// rustc --error-format=json 的输出格式
+#[derive(Deserialize)]
+struct RustcDiagnostic {
+    message: String,
+    level: String,
+    code: Option<RustcCode>,
+    spans: Vec<RustcSpan>,
+    rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RustcCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct RustcSpan {
+    file_name: String,
+    line_start: usize,
+    line_end: usize,
+    column_start: usize,
+    column_end: usize,
+    is_primary: bool,
+    label: Option<String>,
+}
+
+// cargo --message-format=json 的输出格式，只关心编译器消息
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<RustcDiagnostic>,
+}
+
+// 解析命令输出；base 为编译器的工作目录，用于还原诊断中的相对路径
+pub fn parse_output(stdout: &str, stderr: &str, base: &Path) -> StageOutput {
+    let mut output = StageOutput {
+        text: String::new(),
+        diagnostics: Vec::new(),
+        tests: Vec::new(),
+    };
+
+    for line in stdout.lines().chain(stderr.lines()) {
+        if let Some(diagnostic) = parse_json_line(line) {
+            if let Some(rendered) = &diagnostic.rendered {
+                output.text.push_str(rendered);
+            }
+            if !diagnostic.spans.is_empty() {
+                output.diagnostics.push(convert(diagnostic, base));
+            }
+            continue;
+        }
+        if line.starts_with('{') && serde_json::from_str::<CargoMessage>(line).is_ok() {
+            // 构建产物等其他 cargo 消息不属于可读输出
+            continue;
+        }
+        if let Some(test) = parse_test_line(line) {
+            output.tests.push(test);
+        }
+        output.text.push_str(line);
+        output.text.push('\n');
+    }
+
+    output
+}
+
+fn parse_json_line(line: &str) -> Option<RustcDiagnostic> {
+    if !line.starts_with('{') {
+        return None;
+    }
+    if let Ok(message) = serde_json::from_str::<CargoMessage>(line) {
+        return match message.reason.as_str() {
+            "compiler-message" => message.message,
+            _ => None,
+        };
+    }
+    serde_json::from_str::<RustcDiagnostic>(line).ok()
+}
+
+fn convert(diagnostic: RustcDiagnostic, base: &Path) -> Diagnostic {
+    Diagnostic {
+        level: diagnostic.level,
+        message: diagnostic.message,
+        code: diagnostic.code.map(|code| code.code),
+        spans: diagnostic
+            .spans
+            .into_iter()
+            .map(|span| Span {
+                file: base.join(&span.file_name).display().to_string(),
+                line_start: span.line_start,
+                line_end: span.line_end,
+                column_start: span.column_start,
+                column_end: span.column_end,
+                primary: span.is_primary,
+                label: span.label,
+            })
+            .collect(),
+    }
+}
+
+fn parse_test_line(line: &str) -> Option<TestCase> {
+    let rest = line.strip_prefix("test ")?;
+    let (test, outcome) = rest.rsplit_once(" ... ")?;
+    let outcome = outcome.split([' ', ',']).next()?.to_lowercase();
+    match outcome.as_str() {
+        "ok" | "failed" | "ignored" => Some(TestCase {
+            test: test.to_string(),
+            outcome,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,125 @@
+use crate::cli::Args;
+use crate::i18n::t;
+use crate::{evaluator, exercise_path, load_exercise_config, toolchain};
+use log::{error, info};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const CONFIG_FILE: &str = "exercise_config.json";
+const EXERCISES_DIR: &str = "exercises";
+
+// 一项检查的结果：通过时为说明文字，失败时为问题描述与修复建议（可为空）
+type Outcome = Result<String, (String, String)>;
+
+// doctor 命令：检查本地工具链、题目目录与配置文件，对每个问题给出修复建议
+pub fn doctor(args: Args) -> Result<(), String> {
+    args.finish()?;
+
+    let mut checks: Vec<(String, Outcome)> = vec![
+        ("rustc".to_string(), tool("rustc", &["--version"], t!("doctor.fix_rustup"))),
+        ("cargo".to_string(), tool("cargo", &["--version"], t!("doctor.fix_rustup"))),
+        (
+            "clippy".to_string(),
+            tool("cargo", &["clippy", "--version"], t!("doctor.fix_component", component = "clippy")),
+        ),
+        (
+            "rustfmt".to_string(),
+            tool("rustfmt", &["--version"], t!("doctor.fix_component", component = "rustfmt")),
+        ),
+        (EXERCISES_DIR.to_string(), writable(Path::new(EXERCISES_DIR))),
+    ];
+    checks.extend(config_checks());
+
+    let mut problems = 0;
+    for (name, outcome) in &checks {
+        match outcome {
+            Ok(detail) => info!("✔ {:<24} {}", name, detail),
+            Err((problem, fix)) => {
+                problems += 1;
+                error!("✘ {:<24} {}", name, problem);
+                if !fix.is_empty() {
+                    error!("  {:<24} → {}", "", fix);
+                }
+            }
+        }
+    }
+
+    if problems > 0 {
+        return Err(t!("doctor.problems", count = problems));
+    }
+    info!("{}", t!("doctor.ok"));
+    Ok(())
+}
+
+// 运行工具并返回其版本输出的第一行
+fn tool(program: &str, args: &[&str], fix: String) -> Outcome {
+    match Command::new(program).args(args).output() {
+        Ok(output) if output.status.success() => {
+            Ok(String::from_utf8_lossy(&output.stdout).lines().next().unwrap_or_default().to_string())
+        }
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err((stderr.lines().next().unwrap_or_default().trim().to_string(), fix))
+        }
+        Err(_) => Err((t!("doctor.not_installed", program = program), fix)),
+    }
+}
+
+// 评测时需要在题目目录中生成测试程序与 target 目录
+fn writable(dir: &Path) -> Outcome {
+    if !dir.is_dir() {
+        return Err((t!("doctor.missing_dir", path = dir.display()), t!("doctor.fix_cwd")));
+    }
+    let probe = dir.join(format!(".cargotest-doctor-{}", std::process::id()));
+    match fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            Ok(t!("doctor.writable"))
+        }
+        Err(e) => Err((
+            t!("doctor.not_writable", path = dir.display(), error = e),
+            t!("doctor.fix_permissions", path = dir.display()),
+        )),
+    }
+}
+
+// 配置文件能否加载、评分规则是否有效，以及每道题的路径、题型和工具链要求
+fn config_checks() -> Vec<(String, Outcome)> {
+    let config = match load_exercise_config(CONFIG_FILE) {
+        Ok(config) => config,
+        Err(e) => {
+            return vec![(
+                CONFIG_FILE.to_string(),
+                Err((t!("config.load_failed", error = e), t!("doctor.fix_config"))),
+            )]
+        }
+    };
+    let exercises = [&config.easy, &config.normal, &config.hard].into_iter().flatten();
+    let mut checks = vec![(
+        CONFIG_FILE.to_string(),
+        match config.scoring.as_ref().map(|policy| policy.validate()) {
+            Some(Err(e)) => Err((e, t!("doctor.fix_config"))),
+            _ => Ok(t!("doctor.config_loaded", count = exercises.clone().count())),
+        },
+    )];
+
+    for exercise in exercises {
+        let path = exercise_path(exercise);
+        let problem = if !path.exists() {
+            Some((t!("doctor.missing_exercise", path = path.display()), t!("doctor.fix_missing_exercise")))
+        } else if evaluator::registry().get(&exercise.exercise_type).is_none() {
+            Some((
+                t!("evaluate.unknown_type", exercise_type = exercise.exercise_type),
+                t!("doctor.fix_config"),
+            ))
+        } else {
+            // 工具链不满足时的提示本身已包含 rustup 修复命令
+            toolchain::check(exercise).err().map(|e| (e, String::new()))
+        };
+        if let Some(problem) = problem {
+            checks.push((exercise.name.clone(), Err(problem)));
+        }
+    }
+    checks
+}
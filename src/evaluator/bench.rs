@@ -0,0 +1,22 @@
+use super::{clean_target_directory, Context, Evaluator};
+
+// 评测带基准测试的 Cargo 项目：测试通过后再运行 cargo bench，基准程序以非零状态退出即视为超时或失败
+pub struct Bench;
+
+impl Evaluator for Bench {
+    fn stages(&self) -> &'static [&'static str] {
+        &["build", "test", "bench"]
+    }
+
+    fn evaluate(&self, ctx: &mut Context) -> bool {
+        let build_success = ctx.run_cargo("build", &["build", "--release"]);
+        let test_success = build_success && ctx.run_cargo("test", &["test", "--release"]);
+        let bench_success = test_success && ctx.run_cargo("bench", &["bench"]);
+
+        ctx.finish(if bench_success { "PASSED" } else { "FAILED" }, bench_success);
+
+        clean_target_directory(&ctx.path);
+
+        bench_success
+    }
+}
@@ -0,0 +1,24 @@
+use super::{clean_target_directory, Context, Evaluator};
+
+// 评测 Cargo 项目：依次执行 build、test 和 clippy，全部通过才算通过
+pub struct CargoProject;
+
+impl Evaluator for CargoProject {
+    fn stages(&self) -> &'static [&'static str] {
+        &["build", "test", "clippy"]
+    }
+
+    fn evaluate(&self, ctx: &mut Context) -> bool {
+        let build_success = ctx.run_cargo("build", &["build"]);
+        let test_success = ctx.run_cargo("test", &["test"]);
+        let clippy_success = ctx.run_cargo("clippy", &["clippy"]);
+
+        let passed = build_success && test_success && clippy_success;
+
+        ctx.finish(if passed { "PASSED" } else { "FAILED" }, passed);
+
+        clean_target_directory(&ctx.path);
+
+        passed
+    }
+}
@@ -0,0 +1,23 @@
+use super::{clean_target_directory, Context, Evaluator};
+
+// 评测以文档测试为用例的库项目：构建后只运行 cargo test --doc
+pub struct DocTest;
+
+impl Evaluator for DocTest {
+    fn stages(&self) -> &'static [&'static str] {
+        &["build", "doctest"]
+    }
+
+    fn evaluate(&self, ctx: &mut Context) -> bool {
+        let build_success = ctx.run_cargo("build", &["build"]);
+        let doc_success = build_success && ctx.run_cargo("doctest", &["test", "--doc"]);
+
+        let passed = build_success && doc_success;
+
+        ctx.finish(if passed { "PASSED" } else { "FAILED" }, passed);
+
+        clean_target_directory(&ctx.path);
+
+        passed
+    }
+}
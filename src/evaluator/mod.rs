@@ -0,0 +1,354 @@
+mod bench;
+mod cargo_project;
+mod doc_test;
+mod multi_file_module;
+mod single_file;
+mod stdin_stdout;
+
+use crate::diagnostics;
+use crate::event::{Event, EventSink};
+use crate::i18n::{self, t};
+use crate::toolchain;
+use crate::{CaseResult, Exercise};
+use log::{debug, error, info};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::OnceLock;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// 一种题型的评测方式，按配置中的 type 字段在注册表中查找
+pub trait Evaluator: Send + Sync {
+    // 依次执行的评测阶段名称，用于进度显示
+    fn stages(&self) -> &'static [&'static str];
+
+    fn evaluate(&self, ctx: &mut Context) -> bool;
+}
+
+// 评测一道题时的上下文：题目配置、题目路径以及事件输出
+pub struct Context<'a> {
+    pub exercise: &'a Exercise,
+    pub path: PathBuf,
+    pub cases: Vec<CaseResult>,
+    // finish 给出的评测结论
+    pub verdict: Option<&'static str>,
+    // 是否有评测命令因超过 timeout 被终止
+    timed_out: bool,
+    sink: &'a mut dyn EventSink,
+    next_stage: usize,
+    passed_stages: Vec<&'static str>,
+}
+
+pub struct Registry {
+    evaluators: HashMap<&'static str, Box<dyn Evaluator>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry {
+            evaluators: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, kind: &'static str, evaluator: Box<dyn Evaluator>) {
+        self.evaluators.insert(kind, evaluator);
+    }
+
+    pub fn get(&self, kind: &str) -> Option<&dyn Evaluator> {
+        self.evaluators.get(kind).map(|evaluator| evaluator.as_ref())
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Registry {
+        let mut registry = Registry::new();
+        registry.register("single_file", Box::new(single_file::SingleFile));
+        registry.register("cargo_project", Box::new(cargo_project::CargoProject));
+        registry.register("multi_file_module", Box::new(multi_file_module::MultiFileModule));
+        registry.register("doc_test", Box::new(doc_test::DocTest));
+        registry.register("bench", Box::new(bench::Bench));
+        registry.register("stdin_stdout", Box::new(stdin_stdout::StdinStdout));
+        registry
+    }
+}
+
+// 内置题型的注册表
+pub fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::default)
+}
+
+impl<'a> Context<'a> {
+    pub fn new(exercise: &'a Exercise, path: PathBuf, sink: &'a mut dyn EventSink) -> Context<'a> {
+        Context {
+            exercise,
+            path,
+            cases: Vec::new(),
+            verdict: None,
+            timed_out: false,
+            sink,
+            next_stage: 0,
+            passed_stages: Vec::new(),
+        }
+    }
+
+    pub fn emit(&mut self, event: Event) {
+        self.sink.emit(event);
+    }
+
+    pub fn begin_stage(&mut self, stage: &'static str) {
+        let index = self.next_stage;
+        self.next_stage += 1;
+        self.emit(Event::StageStarted {
+            name: self.exercise.name.clone(),
+            stage,
+            index,
+        });
+    }
+
+    pub fn end_stage(&mut self, stage: &'static str, success: bool, output: String) {
+        if success {
+            self.passed_stages.push(stage);
+        }
+        self.emit(Event::StageFinished {
+            name: self.exercise.name.clone(),
+            stage,
+            success,
+            output,
+        });
+    }
+
+    pub fn stage_passed(&self, stage: &str) -> bool {
+        self.passed_stages.contains(&stage)
+    }
+
+    // 为评测命令设置题目要求的环境变量与工具链渠道
+    pub fn apply_environment(&self, command: &mut Command) {
+        command.envs(&self.exercise.env);
+        if let Some(channel) = toolchain::channel(self.exercise) {
+            command.env("RUSTUP_TOOLCHAIN", channel);
+        }
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.exercise.timeout.map(Duration::from_secs)
+    }
+
+    // 执行一个评测阶段，捕获其标准输出与标准错误；命令无法启动时返回 None
+    pub fn run_stage(&mut self, stage: &'static str, command: &mut Command) -> Option<bool> {
+        let name = self.exercise.name.clone();
+        self.begin_stage(stage);
+        self.apply_environment(command);
+        log_command(command);
+        let started = Instant::now();
+        let (output, timed_out) = match output_with_timeout(command.stdin(Stdio::null()), self.timeout()) {
+            Ok(output) => output,
+            Err(e) => {
+                debug!("failed to spawn {:?}: {}", command.get_program(), e);
+                return None;
+            }
+        };
+        debug!("  {} ({} ms)", output.status, started.elapsed().as_millis());
+        let base = command.get_current_dir().map(Path::to_path_buf).unwrap_or_default();
+        let mut parsed = diagnostics::parse_output(
+            &String::from_utf8_lossy(&output.stdout),
+            &String::from_utf8_lossy(&output.stderr),
+            &base,
+        );
+        if timed_out {
+            self.timed_out = true;
+            let message = t!("evaluate.timed_out", seconds = self.exercise.timeout.unwrap_or_default());
+            error!("{}: {}", self.path.display(), message);
+            parsed.text.push_str(&format!("\n{}\n", message));
+        }
+        for diagnostic in parsed.diagnostics {
+            self.emit(Event::Diagnostic {
+                name: name.clone(),
+                stage,
+                diagnostic,
+            });
+        }
+        for case in parsed.tests {
+            self.emit(Event::TestCase {
+                name: name.clone(),
+                case,
+            });
+        }
+        let success = output.status.success() && !timed_out;
+        self.end_stage(stage, success, parsed.text);
+        Some(success)
+    }
+
+    // 在题目目录中运行 cargo 子命令
+    pub fn run_cargo(&mut self, stage: &'static str, args: &[&str]) -> bool {
+        let mut cargo = Command::new("cargo");
+        cargo
+            .args(args)
+            .arg("--message-format=json")
+            .current_dir(&self.path);
+        if !self.exercise.rustc_flags.is_empty() {
+            cargo.env("RUSTFLAGS", rustflags(&self.exercise.rustc_flags));
+        }
+
+        self.run_stage(stage, &mut cargo).unwrap_or(false)
+    }
+
+    pub fn finish(&mut self, verdict: &'static str, passed: bool) -> bool {
+        let verdict = if self.timed_out && !passed { "TIMED OUT" } else { verdict };
+        let message = format!("{}: {}", self.path.display(), i18n::verdict(verdict));
+        if passed {
+            info!("{}", message);
+        } else {
+            error!("{}", message);
+        }
+        self.verdict = Some(verdict);
+        let name = self.exercise.name.clone();
+        self.emit(Event::ExerciseFinished { name, verdict, passed });
+        passed
+    }
+}
+
+// 执行命令并捕获输出；超过 timeout 时终止命令及其启动的全部进程，返回值中的 bool 表示是否超时
+fn output_with_timeout(command: &mut Command, timeout: Option<Duration>) -> io::Result<(Output, bool)> {
+    let Some(timeout) = timeout else {
+        return command.output().map(|output| (output, false));
+    };
+    // 放入单独的进程组，超时时 cargo 启动的测试程序也能一并终止
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(command, 0);
+    let mut child = command.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+    let stdout = read_to_end(child.stdout.take());
+    let stderr = read_to_end(child.stderr.take());
+    let deadline = Instant::now() + timeout;
+    let mut timed_out = false;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            timed_out = true;
+            kill(&mut child);
+            break child.wait()?;
+        }
+        thread::sleep(Duration::from_millis(20));
+    };
+    let output = Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    };
+    Ok((output, timed_out))
+}
+
+// 在后台线程中读完管道，避免子进程因管道写满而阻塞
+fn read_to_end(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+fn kill(child: &mut Child) {
+    #[cfg(unix)]
+    let _ = Command::new("kill").arg("-KILL").arg(format!("-{}", child.id())).status();
+    let _ = child.kill();
+}
+
+// 题目的 rustc 参数追加到已有的 RUSTFLAGS 之后
+fn rustflags(flags: &[String]) -> String {
+    std::env::var("RUSTFLAGS")
+        .ok()
+        .filter(|existing| !existing.trim().is_empty())
+        .into_iter()
+        .chain(flags.iter().cloned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// 记录即将执行的命令、工作目录以及与工具链相关的环境变量
+fn log_command(command: &Command) {
+    if !log::log_enabled!(log::Level::Debug) {
+        return;
+    }
+    let cwd = command
+        .get_current_dir()
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_default();
+    let mut env: Vec<String> = std::env::vars()
+        .filter(|(key, _)| key == "PATH" || key.starts_with("RUST") || key.starts_with("CARGO"))
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    for (key, value) in command.get_envs() {
+        let key = key.to_string_lossy();
+        match value {
+            Some(value) => env.push(format!("{}={}", key, value.to_string_lossy())),
+            None => env.push(format!("{} (removed)", key)),
+        }
+    }
+    debug!("running {:?}", command);
+    debug!("  cwd: {}", cwd.display());
+    debug!("  env: {}", env.join(" "));
+}
+
+// 使用 rustc --test 编译 root 文件并运行测试，结束后删除测试二进制文件
+fn compile_and_test(ctx: &mut Context, root: &Path, test_binary: &Path) -> bool {
+    // 编译测试文件
+    let mut compile = Command::new("rustc");
+    compile
+        .arg("--test")  // 使用 rustc --test 进行编译
+        .arg("--error-format=json")  // 输出结构化诊断，便于定位文件与行号
+        .args(ctx.exercise.edition.iter().flat_map(|edition| ["--edition", edition.as_str()]))
+        .args(&ctx.exercise.rustc_flags)
+        .arg(root)
+        .arg("-o")
+        .arg(test_binary);  // 指定输出文件
+
+    match ctx.run_stage("compile", &mut compile) {
+        Some(true) => {
+            // 编译成功，运行测试二进制文件
+            let test_passed = match ctx.run_stage("test", &mut Command::new(test_binary)) {
+                Some(true) => ctx.finish("TEST PASSED", true),
+                Some(false) => ctx.finish("TEST FAILED", false),
+                None => {
+                    error!("{}", t!("evaluate.run_test_failed", path = root.display()));
+                    ctx.finish("TEST FAILED", false)
+                }
+            };
+
+            // 删除测试二进制文件
+            if let Err(e) = fs::remove_file(test_binary) {
+                error!("{}", t!("evaluate.remove_binary_failed", path = test_binary.display(), error = e));
+            } else {
+                info!("{}", t!("evaluate.removed_binary", path = test_binary.display()));
+            }
+
+            test_passed
+        }
+        // 编译失败
+        Some(false) => ctx.finish("COMPILATION FAILED", false),
+        None => {
+            error!("{}", t!("evaluate.rustc_failed", path = root.display()));
+            ctx.finish("COMPILATION FAILED", false)
+        }
+    }
+}
+
+// 清理 target 目录
+fn clean_target_directory(proj_path: &Path) {
+    let target_dir = proj_path.join("target");
+
+    if target_dir.exists() {
+        if let Err(e) = fs::remove_dir_all(&target_dir) {
+            error!("{}", t!("evaluate.clean_failed", error = e));
+        } else {
+            info!("{}", t!("evaluate.cleaned", path = proj_path.display()));
+        }
+    }
+}
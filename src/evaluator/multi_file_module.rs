@@ -0,0 +1,27 @@
+use super::{compile_and_test, Context, Evaluator};
+use crate::i18n::t;
+use log::error;
+
+// 评测由多个模块文件组成的习题：题目路径为目录，以其中的 lib.rs 或 main.rs 为根模块编译测试
+pub struct MultiFileModule;
+
+const ROOT_FILES: &[&str] = &["lib.rs", "main.rs"];
+
+impl Evaluator for MultiFileModule {
+    fn stages(&self) -> &'static [&'static str] {
+        &["compile", "test"]
+    }
+
+    fn evaluate(&self, ctx: &mut Context) -> bool {
+        let dir = ctx.path.clone();
+        let root = match ROOT_FILES.iter().map(|file| dir.join(file)).find(|path| path.is_file()) {
+            Some(root) => root,
+            None => {
+                error!("{}", t!("evaluate.no_root_module", path = dir.display()));
+                return ctx.finish("COMPILATION FAILED", false);
+            }
+        };
+        let test_binary = dir.join(format!("{}-test", ctx.exercise.name));
+        compile_and_test(ctx, &root, &test_binary)
+    }
+}
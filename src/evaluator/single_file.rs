@@ -0,0 +1,17 @@
+use super::{compile_and_test, Context, Evaluator};
+
+// 评测单文件 Rust 习题（使用 rustc --test 并执行测试）
+pub struct SingleFile;
+
+impl Evaluator for SingleFile {
+    fn stages(&self) -> &'static [&'static str] {
+        &["compile", "test"]
+    }
+
+    fn evaluate(&self, ctx: &mut Context) -> bool {
+        // 获取文件名（不带扩展名）
+        let file_path = ctx.path.clone();
+        let test_binary = file_path.with_extension("");
+        compile_and_test(ctx, &file_path, &test_binary)
+    }
+}
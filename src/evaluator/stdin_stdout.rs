@@ -0,0 +1,206 @@
+use super::{clean_target_directory, log_command, output_with_timeout, rustflags, Context, Evaluator};
+use crate::event::Event;
+use crate::diagnostics::TestCase;
+use crate::i18n::t;
+use crate::{CaseResult, CompareMode};
+use log::error;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+// 差异中最多列出的不一致行数
+const MAX_DIFF_LINES: usize = 10;
+
+// ACM 风格的输入输出评测：编译项目后把 tests/*.in 依次作为标准输入运行程序，
+// 将标准输出与同名的 .out 文件比较
+pub struct StdinStdout;
+
+impl Evaluator for StdinStdout {
+    fn stages(&self) -> &'static [&'static str] {
+        &["build", "judge"]
+    }
+
+    fn evaluate(&self, ctx: &mut Context) -> bool {
+        let passed = ctx.run_cargo("build", &["build", "--release"]) && judge(ctx);
+
+        ctx.finish(if passed { "PASSED" } else { "FAILED" }, passed);
+
+        clean_target_directory(&ctx.path);
+
+        passed
+    }
+}
+
+fn judge(ctx: &mut Context) -> bool {
+    ctx.begin_stage("judge");
+    let judge = ctx.exercise.judge.clone().unwrap_or_default();
+
+    let inputs = match input_files(&ctx.path.join("tests")) {
+        Ok(inputs) if !inputs.is_empty() => inputs,
+        Ok(_) => {
+            let message = t!("judge.no_inputs", path = ctx.path.display());
+            error!("{}", message);
+            ctx.end_stage("judge", false, message);
+            return false;
+        }
+        Err(e) => {
+            let message = t!("judge.read_failed", path = ctx.path.join("tests").display(), error = e);
+            error!("{}", message);
+            ctx.end_stage("judge", false, message);
+            return false;
+        }
+    };
+
+    let mut summary = String::new();
+    for input in inputs {
+        let name = input.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        let case = match run_case(ctx, &input) {
+            Ok(actual) => match fs::read_to_string(input.with_extension("out")) {
+                Ok(expected) => {
+                    let diff = diff(&expected, &actual, judge.compare, judge.tolerance);
+                    CaseResult {
+                        name,
+                        passed: diff.is_none(),
+                        diff,
+                    }
+                }
+                Err(e) => CaseResult {
+                    name,
+                    passed: false,
+                    diff: Some(t!("judge.expected_unreadable", error = e)),
+                },
+            },
+            Err(message) => CaseResult {
+                name,
+                passed: false,
+                diff: Some(message),
+            },
+        };
+
+        if !case.passed {
+            error!(
+                "{}\n{}",
+                t!("judge.case_failed", path = ctx.path.display(), case = case.name),
+                case.diff.as_deref().unwrap_or_default()
+            );
+        }
+        summary.push_str(&format!("{} ... {}\n", case.name, if case.passed { "ok" } else { "FAILED" }));
+        if let Some(diff) = &case.diff {
+            summary.push_str(diff);
+            summary.push('\n');
+        }
+        ctx.emit(Event::TestCase {
+            name: ctx.exercise.name.clone(),
+            case: TestCase {
+                test: case.name.clone(),
+                outcome: if case.passed { "ok" } else { "failed" }.to_string(),
+            },
+        });
+        ctx.cases.push(case);
+    }
+
+    let passed = ctx.cases.iter().all(|case| case.passed);
+    ctx.end_stage("judge", passed, summary);
+    passed
+}
+
+fn input_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut inputs: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("in"))
+        .collect();
+    inputs.sort();
+    Ok(inputs)
+}
+
+// 以输入文件为标准输入运行已编译的程序，返回其标准输出
+fn run_case(ctx: &Context, input: &Path) -> Result<String, String> {
+    let stdin = File::open(input).map_err(|e| t!("judge.open_failed", path = input.display(), error = e))?;
+    let mut command = Command::new("cargo");
+    command
+        .args(["run", "--release", "--quiet"])
+        .current_dir(&ctx.path)
+        .stdin(Stdio::from(stdin));
+    ctx.apply_environment(&mut command);
+    if !ctx.exercise.rustc_flags.is_empty() {
+        command.env("RUSTFLAGS", rustflags(&ctx.exercise.rustc_flags));
+    }
+    log_command(&command);
+
+    let (output, timed_out) =
+        output_with_timeout(&mut command, ctx.timeout()).map_err(|e| t!("judge.run_failed", error = e))?;
+    if timed_out {
+        return Err(t!("judge.timed_out", seconds = ctx.exercise.timeout.unwrap_or_default()));
+    }
+    if !output.status.success() {
+        return Err(format!(
+            "{}\n{}",
+            t!("judge.runtime_error", status = output.status),
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// 比较期望输出与实际输出，一致时返回 None，否则返回逐行差异
+fn diff(expected: &str, actual: &str, mode: CompareMode, tolerance: f64) -> Option<String> {
+    let expected = expected.replace("\r\n", "\n");
+    let actual = actual.replace("\r\n", "\n");
+
+    if text_eq(&expected, &actual, mode, tolerance) {
+        return None;
+    }
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut diff = Vec::new();
+    for line in 0..expected_lines.len().max(actual_lines.len()) {
+        let e = expected_lines.get(line);
+        let a = actual_lines.get(line);
+        let same = match (e, a) {
+            (Some(e), Some(a)) => text_eq(e, a, mode, tolerance),
+            _ => false,
+        };
+        if same {
+            continue;
+        }
+        if diff.len() >= MAX_DIFF_LINES {
+            diff.push("...".to_string());
+            break;
+        }
+        let mut entry = format!("@@ {}", t!("judge.diff_line", line = line + 1));
+        let missing = t!("judge.missing_line");
+        entry.push_str(&format!("\n- {}", e.copied().unwrap_or(&missing)));
+        entry.push_str(&format!("\n+ {}", a.copied().unwrap_or(&missing)));
+        diff.push(entry);
+    }
+    if diff.is_empty() {
+        // 逐行相同但整体不同，只可能是末尾换行或空白的差异
+        diff.push(t!("judge.trailing_whitespace"));
+    }
+    Some(diff.join("\n"))
+}
+
+// whitespace 与 float 模式忽略空白的数量和位置，只比较记号序列
+fn text_eq(expected: &str, actual: &str, mode: CompareMode, tolerance: f64) -> bool {
+    match mode {
+        CompareMode::Exact => expected == actual,
+        CompareMode::Whitespace | CompareMode::Float => {
+            let expected: Vec<&str> = expected.split_whitespace().collect();
+            let actual: Vec<&str> = actual.split_whitespace().collect();
+            expected.len() == actual.len()
+                && expected.iter().zip(&actual).all(|(e, a)| token_eq(e, a, mode, tolerance))
+        }
+    }
+}
+
+// float 模式下两个记号都能解析为浮点数时按容差比较，否则按字符串比较
+fn token_eq(expected: &str, actual: &str, mode: CompareMode, tolerance: f64) -> bool {
+    if mode == CompareMode::Float {
+        if let (Ok(e), Ok(a)) = (expected.parse::<f64>(), actual.parse::<f64>()) {
+            return (e - a).abs() <= tolerance;
+        }
+    }
+    expected == actual
+}
@@ -0,0 +1,69 @@
+use crate::diagnostics::{Diagnostic, TestCase};
+use crate::Statistics;
+use serde::Serialize;
+use std::io::{self, Write};
+
+// 评测过程中产生的进度事件，由终端界面或 JSON 事件流消费
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    ExerciseStarted {
+        name: String,
+        exercise_type: String,
+        stages: usize,
+    },
+    StageStarted {
+        name: String,
+        stage: &'static str,
+        index: usize,
+    },
+    StageFinished {
+        name: String,
+        stage: &'static str,
+        success: bool,
+        output: String,
+    },
+    TestCase {
+        name: String,
+        #[serde(flatten)]
+        case: TestCase,
+    },
+    Diagnostic {
+        name: String,
+        stage: &'static str,
+        #[serde(flatten)]
+        diagnostic: Diagnostic,
+    },
+    ExerciseFinished {
+        name: String,
+        verdict: &'static str,
+        passed: bool,
+    },
+    RunFinished {
+        statistics: Statistics,
+    },
+}
+
+pub trait EventSink {
+    fn emit(&mut self, event: Event);
+}
+
+// 纯文本模式下不需要进度事件，输出全部经由日志
+pub struct Silent;
+
+impl EventSink for Silent {
+    fn emit(&mut self, _event: Event) {}
+}
+
+// --events jsonl：每个事件输出为标准输出上的一行 JSON，供编辑器插件消费
+pub struct JsonLines;
+
+impl EventSink for JsonLines {
+    fn emit(&mut self, event: Event) {
+        let mut stdout = io::stdout().lock();
+        if let Ok(line) = serde_json::to_string(&event) {
+            let _ = writeln!(stdout, "{}", line);
+            let _ = stdout.flush();
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::i18n::t;
+use log::debug;
+use std::path::PathBuf;
+use std::process::Command;
+
+// 在当前目录运行 git 子命令，返回标准输出
+fn git(args: &[&str]) -> Result<String, String> {
+    debug!("running git {}", args.join(" "));
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| t!("git.run_failed", error = e))?;
+    if !output.status.success() {
+        return Err(t!(
+            "git.command_failed",
+            command = args.join(" "),
+            error = String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// 当前 HEAD 的提交哈希，不在 git 仓库中时返回 None
+pub fn head_commit() -> Option<String> {
+    git(&["rev-parse", "HEAD"]).ok().map(|hash| hash.trim().to_string())
+}
+
+// 工作区（含暂存区和未跟踪文件）相对于 git_ref 有改动的文件，路径相对于当前目录
+pub fn changed_paths(git_ref: &str) -> Result<Vec<PathBuf>, String> {
+    let diff = git(&["diff", "--name-only", "--relative", git_ref, "--"])?;
+    let untracked = git(&["ls-files", "--others", "--exclude-standard"])?;
+    Ok(diff
+        .lines()
+        .chain(untracked.lines())
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
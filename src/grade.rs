@@ -0,0 +1,168 @@
+use crate::cli;
+use crate::event::{self, Event, EventSink};
+use crate::i18n::t;
+use crate::{
+    evaluate_exercise, git, load_exercise_config, record_result, save_report_to_json, signing, summarize, ui, ExerciseConfig,
+    ExerciseResult, Report, RunPolicy, EXERCISES_DIR,
+};
+use log::{error, info, warn};
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+// 评测所有题目并生成 report.json
+pub fn grade(mode: &str, options: cli::Options, dashboard: bool) -> Result<(), String> {
+    let mut args = options.args;
+    let changed_since = args.value("--changed-since")?;
+    let previous_report = args.value("--previous-report")?.unwrap_or_else(|| "report.json".to_string());
+    let signing_key = if args.flag("--sign") { Some(signing::Key::from_env()?) } else { None };
+    let policy = RunPolicy {
+        fail_fast: args.flag("--fail-fast"),
+        retries: match args.value("--retries")? {
+            Some(retries) => retries.parse().map_err(|_| t!("cli.invalid_retries", retries = retries))?,
+            None => 0,
+        },
+    };
+    args.finish()?;
+    let start_time = Instant::now();
+
+    
+    let mut config = load_exercise_config("exercise_config.json")
+        .map_err(|e| t!("config.load_failed", error = e))?;
+    if let Some(policy) = &config.scoring {
+        policy.validate()?;
+    }
+    let scoring_config = config.clone();
+
+    let mut report = Report {
+        commit: git::head_commit(),
+        ..Report::default()
+    };
+
+    if let Some(git_ref) = &changed_since {
+        carry_over_unchanged(git_ref, &previous_report, &mut config, &mut report)?;
+    }
+
+    
+    if dashboard {
+        ui::dashboard::run(mode, config, policy, &mut report)
+            .map_err(|e| t!("dashboard.failed", error = e))?;
+    } else if options.events.is_some() {
+        evaluate_exercises_from_config(mode, config, policy, &mut report, &mut event::JsonLines);
+    } else {
+        evaluate_exercises_from_config(mode, config, policy, &mut report, &mut event::Silent);
+    }
+
+    
+    report.statistics.total_time = start_time.elapsed().as_secs();
+    // 沿用的结果先于重新评测的结果计入，汇总时恢复配置中的题目顺序
+    summarize(&scoring_config, &mut report);
+
+    
+    if options.events.is_some() {
+        event::JsonLines.emit(Event::RunFinished {
+            statistics: report.statistics.clone(),
+        });
+    }
+
+    let statistics = &report.statistics;
+    info!("\n{}", t!("summary.title"));
+    info!("{}", t!("summary.total_exercises", count = statistics.total_exercises));
+    info!("{}", t!("summary.total_successes", count = statistics.total_successes));
+    info!("{}", t!("summary.total_failures", count = statistics.total_failures));
+    if statistics.bonus_score > 0 {
+        info!("{}", t!("summary.bonus", score = statistics.bonus_score));
+    }
+    if statistics.late_penalty > 0 {
+        info!("{}", t!("summary.late_penalty", score = statistics.late_penalty));
+    }
+    info!(
+        "{}",
+        t!(
+            "summary.total_score",
+            score = statistics.total_score,
+            max = statistics.max_score,
+            percentage = format!("{:.2}", statistics.percentage)
+        )
+    );
+
+    if let Some(key) = &signing_key {
+        signing::sign(&mut report, &scoring_config, key)?;
+    }
+
+    
+    if let Err(e) = save_report_to_json("report.json", &report) {
+        error!("{}", t!("report.save_failed", error = e));
+    }
+    Ok(())
+}
+
+
+// --changed-since：只保留相对于 git_ref 有改动的题目，其余题目沿用上一份报告中的结果；
+// 题目配置有改动或上一份报告中没有该题时仍会重新评测
+fn carry_over_unchanged(git_ref: &str, previous_report: &str, config: &mut ExerciseConfig, report: &mut Report) -> Result<(), String> {
+    let changed = git::changed_paths(git_ref)?;
+    if changed.iter().any(|path| path == Path::new("exercise_config.json")) {
+        info!("{}", t!("changed.config_changed", git_ref = git_ref));
+        return Ok(());
+    }
+    let previous: Report = File::open(previous_report)
+        .map_err(|e| e.to_string())
+        .and_then(|file| serde_json::from_reader(file).map_err(|e| e.to_string()))
+        .map_err(|e| t!("changed.previous_report_failed", path = previous_report, error = e))?;
+
+    for exercises in [&mut config.easy, &mut config.normal, &mut config.hard] {
+        exercises.retain(|exercise| {
+            let root = PathBuf::from("exercises").join(&exercise.path);
+            if changed.iter().any(|path| path.starts_with(&root)) {
+                return true;
+            }
+            match previous.exercises.iter().find(|result| result.name == exercise.name) {
+                Some(result) => {
+                    record_result(report, ExerciseResult { carried_over: true, ..result.clone() });
+                    false
+                }
+                None => true,
+            }
+        });
+    }
+    let regraded = config.easy.len() + config.normal.len() + config.hard.len();
+    info!(
+        "{}",
+        t!(
+            "changed.summary",
+            regraded = regraded,
+            git_ref = git_ref,
+            carried = report.exercises.len(),
+            path = previous_report
+        )
+    );
+    Ok(())
+}
+
+fn evaluate_exercises_from_config(mode: &str, config: ExerciseConfig, policy: RunPolicy, report: &mut Report, sink: &mut dyn EventSink) {
+    let all_exercises = [config.easy, config.normal, config.hard].concat();
+    for exercise in all_exercises {
+        let result = evaluate_exercise(&exercise, Path::new(EXERCISES_DIR), policy, sink);
+        let passed = result.result;
+        record_result(report, result);
+
+        if policy.fail_fast && !passed {
+            warn!("{}", t!("evaluate.fail_fast", name = exercise.name));
+            break;
+        }
+
+        if mode == "watch" && !ask_to_continue() {
+            break;
+        }
+    }
+}
+
+// 用户确认是否继续
+fn ask_to_continue() -> bool {
+    let mut input = String::new();
+    info!("\n{}", t!("watch.continue"));
+    io::stdin().read_line(&mut input).unwrap();
+    input.trim().to_lowercase() != "q"
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+// 内置的消息目录：(语言标签, YAML 内容)，第一个为缺省语言
+const CATALOGUES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.yaml")),
+    ("zh-CN", include_str!("../locales/zh-CN.yaml")),
+];
+
+static LANG: OnceLock<&'static str> = OnceLock::new();
+static MESSAGES: OnceLock<HashMap<&'static str, HashMap<String, String>>> = OnceLock::new();
+
+// 取出本地化消息并替换其中的 {name} 占位符，例如 t!("summary.total_score", score = 10)
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::message($key).to_string()
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::fill(
+            $crate::i18n::message($key),
+            &[$((stringify!($name), &$value as &dyn std::fmt::Display)),+],
+        )
+    };
+}
+pub use t;
+
+// 选择输出语言：--lang 优先，其次为 LANG 环境变量；只能在输出任何消息之前调用
+pub fn init(lang: Option<&str>) -> Result<(), String> {
+    let lang = match lang {
+        Some(lang) => resolve(lang).ok_or_else(|| {
+            let supported: Vec<&str> = CATALOGUES.iter().map(|(tag, _)| *tag).collect();
+            t!("cli.unsupported_lang", lang = lang, supported = supported.join(", "))
+        })?,
+        None => from_env(),
+    };
+    let _ = LANG.set(lang);
+    Ok(())
+}
+
+pub fn lang() -> &'static str {
+    LANG.get_or_init(from_env)
+}
+
+fn from_env() -> &'static str {
+    std::env::var("LANG").ok().and_then(|lang| resolve(&lang)).unwrap_or(CATALOGUES[0].0)
+}
+
+// zh_CN.UTF-8、zh-cn、zh 等均对应 zh-CN
+fn resolve(lang: &str) -> Option<&'static str> {
+    let lang = lang.split('.').next().unwrap_or_default().replace('_', "-").to_lowercase();
+    CATALOGUES
+        .iter()
+        .map(|(tag, _)| *tag)
+        .find(|tag| tag.to_lowercase() == lang || tag.split('-').next() == lang.split('-').next())
+}
+
+fn catalogues() -> &'static HashMap<&'static str, HashMap<String, String>> {
+    MESSAGES.get_or_init(|| {
+        CATALOGUES
+            .iter()
+            .map(|(tag, text)| (*tag, serde_yaml::from_str(text).expect("message catalogue is valid YAML")))
+            .collect()
+    })
+}
+
+// 当前语言中 key 对应的消息，缺失时依次退回缺省语言和 key 本身
+pub fn message(key: &str) -> &str {
+    let catalogues = catalogues();
+    [lang(), CATALOGUES[0].0]
+        .iter()
+        .find_map(|tag| catalogues.get(tag).and_then(|messages| messages.get(key)))
+        .map_or(key, String::as_str)
+}
+
+// 评测结论（如 "TEST PASSED"）与阶段名称在事件流中保持英文，只在显示时翻译
+pub fn verdict(verdict: &str) -> String {
+    translate_name("verdict", verdict)
+}
+
+pub fn stage(stage: &str) -> String {
+    translate_name("stage", stage)
+}
+
+fn translate_name(prefix: &str, name: &str) -> String {
+    let key = format!("{}.{}", prefix, name.to_lowercase().replace(' ', "_"));
+    match message(&key) {
+        text if text == key => name.to_string(),
+        text => text.to_string(),
+    }
+}
+
+pub fn fill(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut text = template.to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    text
+}
+
+// 配置中可本地化的文本：单个字符串，或语言标签到文本的映射，如 {"en": "...", "zh-CN": "..."}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum Localized {
+    Text(String),
+    Translations(BTreeMap<String, String>),
+}
+
+impl Localized {
+    pub fn get(&self) -> &str {
+        match self {
+            Localized::Text(text) => text,
+            Localized::Translations(translations) => [lang(), CATALOGUES[0].0]
+                .iter()
+                .find_map(|tag| {
+                    translations
+                        .iter()
+                        .find(|(key, _)| resolve(key) == Some(*tag))
+                        .map(|(_, text)| text.as_str())
+                })
+                .or_else(|| translations.values().next().map(String::as_str))
+                .unwrap_or_default(),
+        }
+    }
+}
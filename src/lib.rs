@@ -0,0 +1,321 @@
+pub mod cli;
+pub mod diagnostics;
+pub mod doctor;
+mod evaluator;
+pub mod event;
+mod git;
+pub mod grade;
+pub mod i18n;
+pub mod logging;
+pub mod scaffold;
+pub mod scoring;
+pub mod server;
+pub mod signing;
+mod toolchain;
+pub mod ui;
+pub mod verify;
+
+use event::{Event, EventSink};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use std::io;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Exercise {
+    pub name: String,
+    pub path: String,
+    #[serde(rename = "type")]
+    pub exercise_type: String,  
+    pub score: i32, 
+    // 题目标题与提示，可按语言分别给出
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<i18n::Localized>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<i18n::Localized>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub judge: Option<JudgeConfig>,
+    // 工具链要求，如 "stable >= 1.75" 或 "nightly"，评测前检查
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toolchain: Option<String>,
+    // rustc 直接编译的题型使用的 edition；Cargo 项目以 Cargo.toml 为准
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edition: Option<String>,
+    // 额外的 rustc 参数，Cargo 项目通过 RUSTFLAGS 传递
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rustc_flags: Vec<String>,
+    // 评测命令的额外环境变量
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    // 每条评测命令的时间限制（秒），超时的命令连同其子进程一起被终止
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+}
+
+// stdin_stdout 题型的输出比较方式
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct JudgeConfig {
+    #[serde(default)]
+    pub compare: CompareMode,
+    // float 模式下数值比较的容差
+    #[serde(default = "default_tolerance")]
+    pub tolerance: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareMode {
+    #[default]
+    Exact,
+    Whitespace,
+    Float,
+}
+
+fn default_tolerance() -> f64 {
+    0.0001
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExerciseConfig {
+    pub easy: Vec<Exercise>,
+    pub normal: Vec<Exercise>,
+    pub hard: Vec<Exercise>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scoring: Option<scoring::ScoringPolicy>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExerciseResult {
+    pub name: String,
+    pub result: bool,
+    pub score: i32, 
+    // 未改动而沿用上一份报告中的结果（--changed-since）
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub carried_over: bool,
+    // 按评分规则获得的性能加分，不计入 score
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub bonus: i32,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub performance_passed: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cases: Vec<CaseResult>,
+    // --retries：每次评测的结果，最后一次即为 result
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attempts: Vec<Attempt>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Attempt {
+    pub passed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<String>,
+    pub duration_ms: u64,
+}
+
+// 评测策略：--fail-fast 在第一道未通过的题目后停止评测，--retries 为未通过的题目重新评测的次数
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunPolicy {
+    pub fail_fast: bool,
+    pub retries: u32,
+}
+
+// stdin_stdout 题型中单个输入文件的评测结果，失败时附带期望输出与实际输出的差异
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CaseResult {
+    pub name: String,
+    pub passed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Statistics {
+    pub total_exercises: usize,
+    pub total_successes: usize,
+    pub total_failures: usize,
+    pub total_score: i32,  
+    // 按评分规则可获得的最高分及得分百分比
+    #[serde(default)]
+    pub max_score: i32,
+    #[serde(default)]
+    pub percentage: f64,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub bonus_score: i32,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub late_penalty: i32,
+    pub total_time: u64,
+}
+
+fn is_zero(value: &i32) -> bool {
+    *value == 0
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Report {
+    // 生成报告时所在的提交
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    pub exercises: Vec<ExerciseResult>,
+    pub statistics: Statistics,
+    // --sign：已评测题目源码的 SHA-256 与报告签名
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sources: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<signing::Signature>,
+}
+
+// 题目默认所在的目录，题目配置中的 path 相对于该目录
+pub const EXERCISES_DIR: &str = "./exercises";
+
+impl ExerciseConfig {
+    // 按 easy、normal、hard 的顺序列出全部题目
+    pub fn exercises(&self) -> impl Iterator<Item = &Exercise> + Clone {
+        [&self.easy, &self.normal, &self.hard].into_iter().flatten()
+    }
+}
+
+pub fn load_exercise_config(file_path: &str) -> Result<ExerciseConfig, io::Error> {
+    let file = File::open(file_path)?;
+    let config: ExerciseConfig = serde_json::from_reader(file)?;
+    Ok(config)
+}
+
+// 依次评测 root 目录下配置中的全部题目，返回汇总并计分后的报告
+pub fn evaluate_config(config: &ExerciseConfig, root: &Path, policy: RunPolicy, sink: &mut dyn EventSink) -> Report {
+    let start_time = Instant::now();
+    let mut report = Report::default();
+    for exercise in config.exercises() {
+        let result = evaluate_exercise(exercise, root, policy, sink);
+        let passed = result.result;
+        record_result(&mut report, result);
+        if policy.fail_fast && !passed {
+            warn!("{}", t!("evaluate.fail_fast", name = exercise.name));
+            break;
+        }
+    }
+    report.statistics.total_time = start_time.elapsed().as_secs();
+    summarize(config, &mut report);
+    report
+}
+
+// 统计题目总数，按配置中的顺序排列各题结果，并按评分规则计分
+pub fn summarize(config: &ExerciseConfig, report: &mut Report) {
+    report.statistics.total_exercises = report.statistics.total_successes + report.statistics.total_failures;
+    let order: Vec<&str> = config.exercises().map(|exercise| exercise.name.as_str()).collect();
+    report
+        .exercises
+        .sort_by_key(|result| order.iter().position(|name| *name == result.name));
+    scoring::apply(config, report, chrono::Local::now());
+}
+
+// 将一道题的评测结果计入报告
+pub fn record_result(report: &mut Report, result: ExerciseResult) {
+    if result.result {
+        report.statistics.total_successes += 1;
+    } else {
+        report.statistics.total_failures += 1;
+    }
+
+    report.statistics.total_score += result.score;
+    report.exercises.push(result);
+}
+
+
+// 评测 root 目录（通常为 exercises/）下的题目，未通过时按 --retries 重新评测
+pub fn evaluate_exercise(exercise: &Exercise, root: &Path, policy: RunPolicy, sink: &mut dyn EventSink) -> ExerciseResult {
+    let mut attempts = Vec::new();
+    loop {
+        let mut result = evaluate_exercise_at(exercise, root.join(&exercise.path), sink);
+        attempts.append(&mut result.attempts);
+        if result.result || attempts.len() > policy.retries as usize {
+            // 只有启用 --retries 时才在报告中记录每次评测的结果
+            if policy.retries > 0 {
+                result.attempts = attempts;
+            }
+            return result;
+        }
+        warn!(
+            "{}",
+            t!("evaluate.retrying", name = exercise.name, attempt = attempts.len() + 1, total = policy.retries + 1)
+        );
+    }
+}
+
+// 评测位于指定路径（而非 exercises/ 下）的题目副本
+pub fn evaluate_exercise_at(exercise: &Exercise, path: PathBuf, sink: &mut dyn EventSink) -> ExerciseResult {
+    let start_time = Instant::now();
+    let evaluator = evaluator::registry().get(&exercise.exercise_type);
+    match &exercise.title {
+        Some(title) => info!(
+            "\n{}",
+            t!("evaluate.start_titled", exercise_type = exercise.exercise_type, name = exercise.name, title = title.get())
+        ),
+        None => info!("\n{}", t!("evaluate.start", exercise_type = exercise.exercise_type, name = exercise.name)),
+    }
+    sink.emit(Event::ExerciseStarted {
+        name: exercise.name.clone(),
+        exercise_type: exercise.exercise_type.clone(),
+        stages: evaluator.map_or(0, |evaluator| evaluator.stages().len()),
+    });
+    let mut ctx = evaluator::Context::new(exercise, path, sink);
+    let result = match (evaluator, toolchain::check(exercise)) {
+        (Some(evaluator), Ok(())) => evaluator.evaluate(&mut ctx),
+        (Some(_), Err(message)) => {
+            error!("{}", message);
+            ctx.finish("TOOLCHAIN MISMATCH", false)
+        }
+        (None, _) => {
+            warn!("{}", t!("evaluate.unknown_type", exercise_type = exercise.exercise_type));
+            false
+        }
+    };
+
+    ExerciseResult {
+        name: exercise.name.clone(),
+        result,
+        score: if result { exercise.score } else { 0 },
+        carried_over: false,
+        bonus: 0,
+        performance_passed: ctx.stage_passed(scoring::PERFORMANCE_STAGE),
+        cases: ctx.cases,
+        attempts: vec![Attempt {
+            passed: result,
+            verdict: ctx.verdict.map(str::to_string),
+            duration_ms: start_time.elapsed().as_millis() as u64,
+        }],
+    }
+}
+
+pub fn exercise_path(exercise: &Exercise) -> PathBuf {
+    Path::new(EXERCISES_DIR).join(&exercise.path)
+}
+
+// 题目提示：优先使用配置中的 hint，否则从题目源码注释中的 "Hint:" 行提取
+pub fn exercise_hint(exercise: &Exercise) -> Option<String> {
+    if let Some(hint) = &exercise.hint {
+        return Some(hint.get().to_string());
+    }
+    let path = exercise_path(exercise);
+    let sources = if path.is_dir() {
+        fs::read_dir(path.join("src"))
+            .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+            .unwrap_or_default()
+    } else {
+        vec![path]
+    };
+    sources
+        .iter()
+        .filter_map(|source| fs::read_to_string(source).ok())
+        .flat_map(|text| text.lines().map(str::trim).map(String::from).collect::<Vec<_>>())
+        .find_map(|line| line.strip_prefix("Hint:").map(|hint| hint.trim().to_string()))
+}
+
+// 保存评测报告
+pub fn save_report_to_json(file_name: &str, report: &Report) -> io::Result<()> {
+    let file = File::create(file_name)?;
+    serde_json::to_writer_pretty(file, report)?;
+    Ok(())
+}
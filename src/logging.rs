@@ -0,0 +1,88 @@
+use crate::i18n::t;
+use log::LevelFilter;
+use log4rs::append::console::ConsoleAppender;
+use log4rs::config::{Appender, Config, Deserializers, RawConfig, Root};
+use log4rs::encode::pattern::PatternEncoder;
+use log4rs::Handle;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+pub const LOG_CONFIG_FILE: &str = "log4rs.yaml";
+
+// 名称以 console 开头的 appender 视为终端输出，全屏界面运行时会被关闭
+const CONSOLE_PREFIX: &str = "console";
+
+static LOGGER: OnceLock<(Handle, i32)> = OnceLock::new();
+
+// 根据 log4rs.yaml 初始化日志；verbosity 为 -v 次数减去 -q 次数，非零时覆盖配置文件中的 root 级别
+pub fn init(verbosity: i32, console: bool) -> Result<(), String> {
+    let handle = log4rs::init_config(build_config(verbosity, console)?).map_err(|e| e.to_string())?;
+    let _ = LOGGER.set((handle, verbosity));
+    Ok(())
+}
+
+// 全屏界面退出后重新打开控制台输出
+pub fn restore_console() {
+    if let Some((handle, verbosity)) = LOGGER.get() {
+        match build_config(*verbosity, true) {
+            Ok(config) => handle.set_config(config),
+            Err(e) => eprintln!("{}", t!("logging.restore_failed", error = e)),
+        }
+    }
+}
+
+fn build_config(verbosity: i32, console: bool) -> Result<Config, String> {
+    if Path::new(LOG_CONFIG_FILE).exists() {
+        load_config(LOG_CONFIG_FILE, verbosity, console)
+    } else {
+        default_config(verbosity, console)
+    }
+}
+
+fn load_config(path: &str, verbosity: i32, console: bool) -> Result<Config, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    let raw: RawConfig = serde_yaml::from_str(&text).map_err(|e| format!("{}: {}", path, e))?;
+    let (appenders, errors) = raw.appenders_lossy(&Deserializers::default());
+    if !errors.is_empty() {
+        return Err(format!("{}: {:?}", path, errors));
+    }
+
+    let keep = |name: &str| console || !name.starts_with(CONSOLE_PREFIX);
+    let root = raw.root();
+    let root = Root::builder()
+        .appenders(root.appenders().iter().filter(|name| keep(name)).cloned())
+        .build(level(root.level(), verbosity));
+
+    Config::builder()
+        .appenders(appenders.into_iter().filter(|appender| keep(appender.name())))
+        .loggers(raw.loggers())
+        .build(root)
+        .map_err(|e| format!("{}: {}", path, e))
+}
+
+// 缺少配置文件时只输出到控制台，格式与原先的 println! 一致
+fn default_config(verbosity: i32, console: bool) -> Result<Config, String> {
+    let mut builder = Config::builder();
+    let mut root = Root::builder();
+    if console {
+        let stdout = ConsoleAppender::builder()
+            .encoder(Box::new(PatternEncoder::new("{m}{n}")))
+            .build();
+        builder = builder.appender(Appender::builder().build(CONSOLE_PREFIX, Box::new(stdout)));
+        root = root.appender(CONSOLE_PREFIX);
+    }
+    builder
+        .build(root.build(level(LevelFilter::Info, verbosity)))
+        .map_err(|e| e.to_string())
+}
+
+fn level(configured: LevelFilter, verbosity: i32) -> LevelFilter {
+    match verbosity {
+        0 => configured,
+        1 => LevelFilter::Debug,
+        v if v > 1 => LevelFilter::Trace,
+        -1 => LevelFilter::Warn,
+        _ => LevelFilter::Error,
+    }
+}
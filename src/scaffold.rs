@@ -0,0 +1,142 @@
+use crate::cli::Args;
+use crate::i18n::t;
+use crate::{load_exercise_config, Exercise};
+use log::info;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE: &str = "exercise_config.json";
+
+// 各难度题目的默认分值，与 README 中的评分说明一致
+const LEVEL_SCORES: &[(&str, i32)] = &[("easy", 1), ("normal", 6), ("hard", 10)];
+
+// 模板文件：(模板内容, 相对于题目目录的输出路径)
+type Template = (&'static str, &'static str);
+
+const SINGLE_FILE: &[Template] = &[(include_str!("../templates/single_file.rs.tmpl"), "")];
+
+const CARGO_PROJECT: &[Template] = &[
+    (include_str!("../templates/cargo_project/Cargo.toml.tmpl"), "Cargo.toml"),
+    (include_str!("../templates/cargo_project/main.rs.tmpl"), "src/main.rs"),
+    (include_str!("../templates/cargo_project/solution.rs.tmpl"), "src/{{name}}.rs"),
+    (include_str!("../templates/cargo_project/tests.rs.tmpl"), "src/tests.rs"),
+];
+
+const STDIN_STDOUT: &[Template] = &[
+    (include_str!("../templates/stdin_stdout/Cargo.toml.tmpl"), "Cargo.toml"),
+    (include_str!("../templates/stdin_stdout/main.rs.tmpl"), "src/main.rs"),
+    (include_str!("../templates/stdin_stdout/solution.rs.tmpl"), "src/{{name}}.rs"),
+    (include_str!("../templates/stdin_stdout/1.in.tmpl"), "tests/1.in"),
+    (include_str!("../templates/stdin_stdout/1.out.tmpl"), "tests/1.out"),
+];
+
+// new-exercise 命令：按模板生成题目骨架并登记到 exercise_config.json
+pub fn new_exercise(mut args: Args) -> Result<(), String> {
+    let level = args.value("--level")?.ok_or_else(|| t!("scaffold.level_required"))?;
+    let name = args.value("--name")?.ok_or_else(|| t!("scaffold.name_required"))?;
+    let exercise_type = args.value("--type")?.unwrap_or_else(|| "cargo_project".to_string());
+    let function = args.value("--function")?.unwrap_or_else(|| name.clone());
+    let score = match args.value("--score")? {
+        Some(score) => score.parse().map_err(|_| t!("scaffold.invalid_score", score = score))?,
+        None => default_score(&level)?,
+    };
+    args.finish()?;
+
+    for identifier in [&name, &function] {
+        if !is_identifier(identifier) {
+            return Err(t!("scaffold.invalid_identifier", name = identifier));
+        }
+    }
+
+    let templates = match exercise_type.as_str() {
+        "single_file" => SINGLE_FILE,
+        "cargo_project" => CARGO_PROJECT,
+        "stdin_stdout" => STDIN_STDOUT,
+        other => return Err(t!("scaffold.no_template", exercise_type = other)),
+    };
+
+    let mut config = load_exercise_config(CONFIG_FILE).map_err(|e| t!("config.load_failed", error = e))?;
+    let (config_name, path) = if exercise_type == "single_file" {
+        (format!("{}.rs", name), format!("{}/{}.rs", level, name))
+    } else {
+        (name.clone(), format!("{}/{}", level, name))
+    };
+    let exercises = match level.as_str() {
+        "easy" => &mut config.easy,
+        "normal" => &mut config.normal,
+        "hard" => &mut config.hard,
+        other => return Err(t!("scaffold.unknown_level", level = other)),
+    };
+    if exercises.iter().any(|exercise| exercise.name == config_name) {
+        return Err(t!("scaffold.already_registered", name = config_name, path = CONFIG_FILE));
+    }
+
+    let target = Path::new("exercises").join(&path);
+    if target.exists() {
+        return Err(t!("scaffold.already_exists", path = target.display()));
+    }
+
+    let render = |text: &str| {
+        text.replace("{{name}}", &name)
+            .replace("{{function}}", &function)
+            .replace("{{title}}", &title(&name))
+    };
+    for (template, output) in templates {
+        let file: PathBuf = if output.is_empty() { target.clone() } else { target.join(render(output)) };
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent).map_err(|e| t!("fs.create_failed", path = parent.display(), error = e))?;
+        }
+        fs::write(&file, render(template)).map_err(|e| t!("fs.write_failed", path = file.display(), error = e))?;
+        info!("{}", t!("scaffold.created", path = file.display()));
+    }
+
+    exercises.push(Exercise {
+        name: config_name.clone(),
+        path,
+        exercise_type,
+        score,
+        title: None,
+        hint: None,
+        judge: None,
+        toolchain: None,
+        edition: None,
+        rustc_flags: Vec::new(),
+        env: BTreeMap::new(),
+        timeout: None,
+    });
+    let mut text = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    text.push('\n');
+    fs::write(CONFIG_FILE, text).map_err(|e| t!("fs.write_failed", path = CONFIG_FILE, error = e))?;
+    info!("{}", t!("scaffold.registered", name = config_name, path = CONFIG_FILE, level = level, score = score));
+    Ok(())
+}
+
+fn default_score(level: &str) -> Result<i32, String> {
+    LEVEL_SCORES
+        .iter()
+        .find(|(name, _)| *name == level)
+        .map(|(_, score)| *score)
+        .ok_or_else(|| t!("scaffold.unknown_level", level = level))
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+// foo_bar -> Foo Bar，用作单文件题目注释中的标题
+fn title(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,115 @@
+use crate::i18n::t;
+use crate::{evaluator, ExerciseConfig, Report};
+use chrono::{DateTime, FixedOffset, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// 提供性能加分的评测阶段（bench 题型的 cargo bench）
+pub const PERFORMANCE_STAGE: &str = "bench";
+
+// exercise_config.json 中的 scoring 字段，未配置时按各题分值直接求和
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ScoringPolicy {
+    // 截止时间（RFC 3339），超过后按天扣分
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<String>,
+    // 每迟交一天（不足一天按一天计）扣除总分的比例
+    #[serde(default)]
+    pub late_penalty_per_day: f64,
+    // 迟交扣分比例的上限
+    #[serde(default = "default_max_late_penalty")]
+    pub max_late_penalty: f64,
+    // 通过性能阶段的题目额外获得的分数
+    #[serde(default)]
+    pub performance_bonus: i32,
+    // 各难度得分上限，如 {"easy": 20}
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub level_caps: BTreeMap<String, i32>,
+}
+
+fn default_max_late_penalty() -> f64 {
+    1.0
+}
+
+impl ScoringPolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        self.deadline()?;
+        if !(0.0..=1.0).contains(&self.late_penalty_per_day) || !(0.0..=1.0).contains(&self.max_late_penalty) {
+            return Err(t!("scoring.invalid_penalty"));
+        }
+        if let Some(level) = self.level_caps.keys().find(|level| !matches!(level.as_str(), "easy" | "normal" | "hard")) {
+            return Err(t!("scoring.unknown_level", level = level));
+        }
+        Ok(())
+    }
+
+    fn deadline(&self) -> Result<Option<DateTime<FixedOffset>>, String> {
+        self.deadline
+            .as_deref()
+            .map(|deadline| {
+                DateTime::parse_from_rfc3339(deadline).map_err(|e| t!("scoring.invalid_deadline", deadline = deadline, error = e))
+            })
+            .transpose()
+    }
+
+    // 在 now 时刻评测时的迟交扣分比例
+    fn late_fraction(&self, now: DateTime<Local>) -> f64 {
+        let Ok(Some(deadline)) = self.deadline() else {
+            return 0.0;
+        };
+        let late = now.signed_duration_since(deadline);
+        if late <= chrono::Duration::zero() {
+            return 0.0;
+        }
+        let days = (late.num_seconds() as f64 / 86400.0).ceil();
+        (days * self.late_penalty_per_day).min(self.max_late_penalty)
+    }
+
+    fn cap(&self, level: &str, score: i32) -> i32 {
+        match self.level_caps.get(level) {
+            Some(cap) => score.min(*cap),
+            None => score,
+        }
+    }
+}
+
+// 按评分规则重新计算报告中的总分、满分和得分率
+pub fn apply(config: &ExerciseConfig, report: &mut Report, now: DateTime<Local>) {
+    let policy = config.scoring.clone().unwrap_or_default();
+    let levels = [("easy", &config.easy), ("normal", &config.normal), ("hard", &config.hard)];
+
+    let mut max_score = 0;
+    let mut total = 0;
+    let mut bonus = 0;
+    for (level, exercises) in levels {
+        let mut level_max = 0;
+        let mut level_score = 0;
+        for exercise in exercises {
+            let has_performance_stage = evaluator::registry()
+                .get(&exercise.exercise_type)
+                .is_some_and(|evaluator| evaluator.stages().contains(&PERFORMANCE_STAGE));
+            level_max += exercise.score + if has_performance_stage { policy.performance_bonus } else { 0 };
+
+            let Some(result) = report.exercises.iter_mut().find(|result| result.name == exercise.name) else {
+                continue;
+            };
+            result.bonus = if result.performance_passed { policy.performance_bonus } else { 0 };
+            bonus += result.bonus;
+            level_score += result.score + result.bonus;
+        }
+        max_score += policy.cap(level, level_max);
+        total += policy.cap(level, level_score);
+    }
+
+    let late_penalty = (total as f64 * policy.late_fraction(now)).round() as i32;
+    let statistics = &mut report.statistics;
+    statistics.bonus_score = bonus;
+    statistics.late_penalty = late_penalty;
+    statistics.total_score = total - late_penalty;
+    statistics.max_score = max_score;
+    statistics.percentage = if max_score > 0 {
+        (statistics.total_score as f64 * 10000.0 / max_score as f64).round() / 100.0
+    } else {
+        0.0
+    };
+}
@@ -0,0 +1,332 @@
+use crate::cli::Args;
+use crate::i18n::{self, t};
+use crate::Report;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+const DEFAULT_ADDR: &str = "127.0.0.1:8080";
+const DEFAULT_DATA_DIR: &str = "results";
+const MAX_UPLOAD_BYTES: u64 = 1024 * 1024;
+
+// 一名学生最近一次上传的评测报告
+#[derive(Serialize, Deserialize)]
+struct Submission {
+    student: String,
+    submitted_at: String,
+    report: Report,
+}
+
+#[derive(Serialize)]
+struct LeaderboardEntry<'a> {
+    rank: usize,
+    student: &'a str,
+    total_score: i32,
+    total_successes: usize,
+    total_exercises: usize,
+    total_time: u64,
+    submitted_at: &'a str,
+}
+
+#[derive(Serialize)]
+struct ExercisePassRate {
+    name: String,
+    submissions: usize,
+    passes: usize,
+    pass_rate: f64,
+}
+
+struct Store {
+    dir: PathBuf,
+    submissions: BTreeMap<String, Submission>,
+}
+
+// serve 命令：在本地启动结果服务器，接收学生上传的 report.json 并展示排行榜和各题通过率
+pub fn serve(mut args: Args) -> Result<(), String> {
+    let addr = args.value("--addr")?.unwrap_or_else(|| DEFAULT_ADDR.to_string());
+    let dir = PathBuf::from(args.value("--data")?.unwrap_or_else(|| DEFAULT_DATA_DIR.to_string()));
+    args.finish()?;
+
+    let mut store = Store::open(&dir)?;
+    let server = Server::http(&addr).map_err(|e| t!("server.listen_failed", addr = addr, error = e))?;
+    info!(
+        "{}",
+        t!("server.serving", addr = addr, count = store.submissions.len(), path = dir.display())
+    );
+    info!("{}", t!("server.upload_hint", addr = addr));
+
+    for request in server.incoming_requests() {
+        handle(&mut store, request);
+    }
+    Ok(())
+}
+
+fn handle(store: &mut Store, mut request: Request) {
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+    info!("{} {}", request.method(), url);
+
+    let response = match (request.method(), path) {
+        (Method::Get, "/") => html(store.render_html()),
+        (Method::Get, "/api/leaderboard") => json(&store.leaderboard()),
+        (Method::Get, "/api/exercises") => json(&store.pass_rates()),
+        (Method::Post, "/api/reports") => {
+            let student = query_param(query, "student").or_else(|| header(&request, "X-Student"));
+            match upload(store, student, &mut request) {
+                Ok(body) => json(&body).with_status_code(201),
+                Err(message) => {
+                    warn!("{}", t!("server.rejected", error = message));
+                    text(&message).with_status_code(400)
+                }
+            }
+        }
+        (_, "/" | "/api/leaderboard" | "/api/exercises" | "/api/reports") => {
+            text(&t!("server.method_not_allowed")).with_status_code(405)
+        }
+        _ => text(&t!("server.not_found")).with_status_code(404),
+    };
+
+    if let Err(e) = request.respond(response) {
+        error!("{}", t!("server.respond_failed", url = url, error = e));
+    }
+}
+
+fn upload(store: &mut Store, student: Option<String>, request: &mut Request) -> Result<serde_json::Value, String> {
+    let student = student.ok_or_else(|| t!("server.missing_student"))?;
+    if !valid_student(&student) {
+        return Err(t!("server.invalid_student", student = student));
+    }
+
+    let mut body = String::new();
+    request
+        .as_reader()
+        .take(MAX_UPLOAD_BYTES + 1)
+        .read_to_string(&mut body)
+        .map_err(|e| t!("server.read_body_failed", error = e))?;
+    if body.len() as u64 > MAX_UPLOAD_BYTES {
+        return Err(t!("server.report_too_large"));
+    }
+    let report: Report = serde_json::from_str(&body).map_err(|e| t!("server.invalid_report", error = e))?;
+
+    let score = report.statistics.total_score;
+    store.save(Submission {
+        student: student.clone(),
+        submitted_at: chrono::Local::now().to_rfc3339(),
+        report,
+    })?;
+    info!("{}", t!("server.stored", student = student, score = score));
+
+    let rank = store
+        .leaderboard()
+        .iter()
+        .find(|entry| entry.student == student)
+        .map(|entry| entry.rank);
+    Ok(serde_json::json!({ "student": student, "total_score": score, "rank": rank }))
+}
+
+impl Store {
+    fn open(dir: &Path) -> Result<Store, String> {
+        fs::create_dir_all(dir).map_err(|e| t!("fs.create_failed", path = dir.display(), error = e))?;
+        let mut submissions = BTreeMap::new();
+        let entries = fs::read_dir(dir).map_err(|e| t!("fs.read_failed", path = dir.display(), error = e))?;
+        for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let loaded = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| serde_json::from_str::<Submission>(&text).map_err(|e| e.to_string()));
+            match loaded {
+                Ok(submission) => {
+                    submissions.insert(submission.student.clone(), submission);
+                }
+                Err(e) => warn!("{}", t!("server.skipping", path = path.display(), error = e)),
+            }
+        }
+        Ok(Store {
+            dir: dir.to_path_buf(),
+            submissions,
+        })
+    }
+
+    fn save(&mut self, submission: Submission) -> Result<(), String> {
+        let path = self.dir.join(format!("{}.json", submission.student));
+        let text = serde_json::to_string_pretty(&submission).map_err(|e| e.to_string())?;
+        fs::write(&path, text).map_err(|e| t!("fs.write_failed", path = path.display(), error = e))?;
+        self.submissions.insert(submission.student.clone(), submission);
+        Ok(())
+    }
+
+    // 按总分降序、用时升序、提交时间升序排名
+    fn leaderboard(&self) -> Vec<LeaderboardEntry<'_>> {
+        let mut submissions: Vec<&Submission> = self.submissions.values().collect();
+        submissions.sort_by(|a, b| {
+            let (a_stats, b_stats) = (&a.report.statistics, &b.report.statistics);
+            b_stats
+                .total_score
+                .cmp(&a_stats.total_score)
+                .then(a_stats.total_time.cmp(&b_stats.total_time))
+                .then(a.submitted_at.cmp(&b.submitted_at))
+        });
+        submissions
+            .into_iter()
+            .enumerate()
+            .map(|(index, submission)| LeaderboardEntry {
+                rank: index + 1,
+                student: &submission.student,
+                total_score: submission.report.statistics.total_score,
+                total_successes: submission.report.statistics.total_successes,
+                total_exercises: submission.report.statistics.total_exercises,
+                total_time: submission.report.statistics.total_time,
+                submitted_at: &submission.submitted_at,
+            })
+            .collect()
+    }
+
+    // 各题通过率，题目顺序与首次出现在报告中的顺序一致
+    fn pass_rates(&self) -> Vec<ExercisePassRate> {
+        let mut rates: Vec<ExercisePassRate> = Vec::new();
+        for submission in self.submissions.values() {
+            for result in &submission.report.exercises {
+                let index = match rates.iter().position(|rate| rate.name == result.name) {
+                    Some(index) => index,
+                    None => {
+                        rates.push(ExercisePassRate {
+                            name: result.name.clone(),
+                            submissions: 0,
+                            passes: 0,
+                            pass_rate: 0.0,
+                        });
+                        rates.len() - 1
+                    }
+                };
+                rates[index].submissions += 1;
+                if result.result {
+                    rates[index].passes += 1;
+                }
+            }
+        }
+        for rate in &mut rates {
+            rate.pass_rate = rate.passes as f64 / rate.submissions as f64;
+        }
+        rates
+    }
+
+    fn render_html(&self) -> String {
+        let mut leaderboard = String::new();
+        for entry in self.leaderboard() {
+            leaderboard.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}/{}</td><td>{}s</td><td>{}</td></tr>\n",
+                entry.rank,
+                escape(entry.student),
+                entry.total_score,
+                entry.total_successes,
+                entry.total_exercises,
+                entry.total_time,
+                escape(entry.submitted_at)
+            ));
+        }
+        let mut exercises = String::new();
+        for rate in self.pass_rates() {
+            let percent = rate.pass_rate * 100.0;
+            exercises.push_str(&format!(
+                "<tr><td>{}</td><td>{}/{}</td><td><div class=\"bar\"><div style=\"width:{:.0}%\"></div></div> {:.0}%</td></tr>\n",
+                escape(&rate.name),
+                rate.passes,
+                rate.submissions,
+                percent,
+                percent
+            ));
+        }
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; margin-bottom: 2em; }}
+th, td {{ border: 1px solid #ccc; padding: 4px 10px; text-align: left; }}
+th {{ background: #f0f0f0; }}
+.bar {{ display: inline-block; width: 120px; height: 10px; background: #eee; }}
+.bar div {{ height: 100%; background: #4caf50; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<table>
+<tr><th>#</th><th>{student}</th><th>{score}</th><th>{passed}</th><th>{time}</th><th>{submitted}</th></tr>
+{leaderboard}</table>
+<h2>{pass_rates}</h2>
+<table>
+<tr><th>{exercise}</th><th>{passed}</th><th>{pass_rate}</th></tr>
+{exercises}</table>
+</body>
+</html>
+"#,
+            lang = i18n::lang(),
+            title = t!("server.html_leaderboard"),
+            student = t!("server.html_student"),
+            score = t!("server.html_score"),
+            passed = t!("server.html_passed"),
+            time = t!("server.html_time"),
+            submitted = t!("server.html_submitted"),
+            pass_rates = t!("server.html_pass_rates"),
+            exercise = t!("server.html_exercise"),
+            pass_rate = t!("server.html_pass_rate"),
+        )
+    }
+}
+
+// 学生名作为文件名使用，只允许字母、数字、'-'、'_' 和 '.'
+fn valid_student(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 64
+        && !name.starts_with('.')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn query_param(query: &str, key: &str) -> Option<String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(name, _)| *name == key)
+        .map(|(_, value)| value.to_string())
+}
+
+fn header(request: &Request, name: &'static str) -> Option<String> {
+    request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv(name))
+        .map(|header| header.value.as_str().to_string())
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn with_content_type(body: String, content_type: &str) -> Response<std::io::Cursor<Vec<u8>>> {
+    let header = Header::from_bytes("Content-Type", content_type).expect("valid header");
+    Response::from_string(body).with_header(header)
+}
+
+fn html(body: String) -> Response<std::io::Cursor<Vec<u8>>> {
+    with_content_type(body, "text/html; charset=utf-8")
+}
+
+fn json<T: Serialize>(value: &T) -> Response<std::io::Cursor<Vec<u8>>> {
+    with_content_type(serde_json::to_string_pretty(value).unwrap_or_default(), "application/json")
+}
+
+fn text(body: &str) -> Response<std::io::Cursor<Vec<u8>>> {
+    with_content_type(body.to_string(), "text/plain; charset=utf-8")
+}
@@ -0,0 +1,191 @@
+use crate::cli::Args;
+use crate::i18n::t;
+use crate::{load_exercise_config, ExerciseConfig, Report};
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use hmac::{Hmac, Mac};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::path::Path;
+
+// 签名密钥：hmac:<共享密钥> 或 ed25519:<64 位十六进制私钥种子>
+pub const KEY_ENV: &str = "CARGOTEST_SIGNING_KEY";
+
+const HMAC_SHA256: &str = "hmac-sha256";
+const ED25519: &str = "ed25519";
+
+// 报告签名，覆盖除 signature 以外的全部报告内容（包括 sources 中的源码哈希）
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Signature {
+    pub algorithm: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    pub value: String,
+}
+
+pub enum Key {
+    Hmac(Vec<u8>),
+    Ed25519(Box<SigningKey>),
+}
+
+impl Key {
+    pub fn from_env() -> Result<Key, String> {
+        let text = std::env::var(KEY_ENV).map_err(|_| t!("signing.key_missing", env = KEY_ENV))?;
+        match text.split_once(':') {
+            Some(("hmac", secret)) if !secret.is_empty() => Ok(Key::Hmac(secret.as_bytes().to_vec())),
+            Some(("ed25519", seed)) => {
+                let seed: [u8; 32] = hex::decode(seed.trim())
+                    .ok()
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .ok_or_else(|| t!("signing.invalid_seed", env = KEY_ENV))?;
+                Ok(Key::Ed25519(Box::new(SigningKey::from_bytes(&seed))))
+            }
+            _ => Err(t!("signing.invalid_key", env = KEY_ENV)),
+        }
+    }
+
+    fn sign(&self, payload: &[u8]) -> Signature {
+        match self {
+            Key::Hmac(secret) => {
+                let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+                mac.update(payload);
+                Signature {
+                    algorithm: HMAC_SHA256.to_string(),
+                    public_key: None,
+                    value: hex::encode(mac.finalize().into_bytes()),
+                }
+            }
+            Key::Ed25519(key) => Signature {
+                algorithm: ED25519.to_string(),
+                public_key: Some(hex::encode(key.verifying_key().as_bytes())),
+                value: hex::encode(key.sign(payload).to_bytes()),
+            },
+        }
+    }
+}
+
+// 记录已评测题目的源码哈希并签名报告
+pub fn sign(report: &mut Report, config: &ExerciseConfig, key: &Key) -> Result<(), String> {
+    report.sources = source_hashes(report, config)?;
+    report.signature = None;
+    let payload = serde_json::to_vec(report).map_err(|e| e.to_string())?;
+    let signature = key.sign(&payload);
+    match &signature.public_key {
+        Some(public_key) => info!("{}", t!("signing.signed_ed25519", public_key = public_key)),
+        None => info!("{}", t!("signing.signed_hmac")),
+    }
+    report.signature = Some(signature);
+    Ok(())
+}
+
+// 报告中各题目录下所有源文件（不含 target/）的 SHA-256，键为相对于仓库根目录的路径
+fn source_hashes(report: &Report, config: &ExerciseConfig) -> Result<BTreeMap<String, String>, String> {
+    let mut hashes = BTreeMap::new();
+    for result in &report.exercises {
+        let exercise = [&config.easy, &config.normal, &config.hard]
+            .into_iter()
+            .flatten()
+            .find(|exercise| exercise.name == result.name)
+            .ok_or_else(|| t!("signing.unknown_exercise", name = result.name))?;
+        hash_path(&Path::new("exercises").join(&exercise.path), &mut hashes)?;
+    }
+    Ok(hashes)
+}
+
+fn hash_path(path: &Path, hashes: &mut BTreeMap<String, String>) -> Result<(), String> {
+    if path.is_file() {
+        let content = fs::read(path).map_err(|e| t!("fs.read_failed", path = path.display(), error = e))?;
+        let key = path.to_string_lossy().replace('\\', "/");
+        hashes.insert(key, hex::encode(Sha256::digest(&content)));
+        return Ok(());
+    }
+    let entries = fs::read_dir(path).map_err(|e| t!("fs.read_failed", path = path.display(), error = e))?;
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        if entry.file_name() != "target" {
+            hash_path(&entry.path(), hashes)?;
+        }
+    }
+    Ok(())
+}
+
+// verify-report 命令：校验报告签名，并与当前工作区中的源码重新比对哈希
+pub fn verify_report(mut args: Args) -> Result<(), String> {
+    let path = args.value("--report")?.unwrap_or_else(|| "report.json".to_string());
+    let public_key = args.value("--public-key")?;
+    args.finish()?;
+
+    let mut report: Report = File::open(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|file| serde_json::from_reader(file).map_err(|e| e.to_string()))
+        .map_err(|e| t!("fs.read_failed", path = path, error = e))?;
+    let signature = report.signature.take().ok_or_else(|| t!("signing.unsigned", path = path))?;
+    let payload = serde_json::to_vec(&report).map_err(|e| e.to_string())?;
+
+    let valid = match signature.algorithm.as_str() {
+        HMAC_SHA256 => {
+            let Key::Hmac(secret) = Key::from_env()? else {
+                return Err(t!("signing.key_mismatch", algorithm = HMAC_SHA256, env = KEY_ENV));
+            };
+            let expected = hex::decode(&signature.value).map_err(|e| t!("signing.malformed", error = e))?;
+            let mut mac = Hmac::<Sha256>::new_from_slice(&secret).expect("HMAC accepts keys of any length");
+            mac.update(&payload);
+            mac.verify_slice(&expected).is_ok()
+        }
+        ED25519 => {
+            let trusted = match public_key {
+                Some(public_key) => verifying_key(&public_key)?,
+                None => match Key::from_env()? {
+                    Key::Ed25519(key) => key.verifying_key(),
+                    Key::Hmac(_) => return Err(t!("signing.key_mismatch", algorithm = ED25519, env = KEY_ENV)),
+                },
+            };
+            let value: [u8; 64] = hex::decode(&signature.value)
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| t!("signing.malformed", error = signature.value))?;
+            trusted.verify(&payload, &ed25519_dalek::Signature::from_bytes(&value)).is_ok()
+        }
+        other => return Err(t!("signing.unknown_algorithm", algorithm = other)),
+    };
+    if !valid {
+        return Err(t!("signing.invalid_signature", path = path));
+    }
+    info!("{}", t!("signing.signature_valid", algorithm = signature.algorithm));
+
+    // 源码哈希与当前工作区比对，找出提交报告后被修改、删除或新增的文件
+    let config = load_exercise_config("exercise_config.json").map_err(|e| t!("config.load_failed", error = e))?;
+    let current = source_hashes(&report, &config)?;
+    let mut mismatches = 0;
+    for (file, hash) in &report.sources {
+        match current.get(file) {
+            Some(current) if current == hash => {}
+            Some(_) => {
+                mismatches += 1;
+                error!("{}", t!("signing.source_modified", path = file));
+            }
+            None => {
+                mismatches += 1;
+                error!("{}", t!("signing.source_missing", path = file));
+            }
+        }
+    }
+    for file in current.keys().filter(|file| !report.sources.contains_key(*file)) {
+        mismatches += 1;
+        error!("{}", t!("signing.source_added", path = file));
+    }
+    if mismatches > 0 {
+        return Err(t!("signing.sources_mismatch", count = mismatches));
+    }
+    info!("{}", t!("signing.sources_match", count = report.sources.len()));
+    Ok(())
+}
+
+fn verifying_key(text: &str) -> Result<VerifyingKey, String> {
+    hex::decode(text.trim())
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok())
+        .ok_or_else(|| t!("signing.invalid_public_key", key = text))
+}
@@ -0,0 +1,145 @@
+use crate::i18n::t;
+use crate::Exercise;
+use log::debug;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+
+const CHANNELS: &[&str] = &["stable", "beta", "nightly"];
+
+// 题目配置中的 toolchain 字段，如 "stable >= 1.75"、"nightly"、">= 1.70"
+struct Requirement {
+    channel: Option<String>,
+    version: Option<(Ordering, bool, Version)>,
+}
+
+// rustc 版本号 (major, minor, patch)
+type Version = (u32, u32, u32);
+
+#[derive(Clone, Debug)]
+struct Installed {
+    version: Version,
+    release: String,
+    channel: &'static str,
+}
+
+impl Requirement {
+    fn parse(text: &str) -> Result<Requirement, String> {
+        let invalid = || t!("toolchain.invalid_requirement", requirement = text);
+        let mut rest = text.trim();
+        let mut requirement = Requirement {
+            channel: None,
+            version: None,
+        };
+        if let Some(channel) = CHANNELS.iter().find(|channel| rest.starts_with(**channel)) {
+            requirement.channel = Some(channel.to_string());
+            rest = rest[channel.len()..].trim_start();
+        }
+        if rest.is_empty() {
+            return if requirement.channel.is_some() { Ok(requirement) } else { Err(invalid()) };
+        }
+        // (比较结果, 是否允许相等)；不带运算符的版本号视为最低版本
+        let (ordering, or_equal, version) = [
+            (">=", Ordering::Greater, true),
+            ("<=", Ordering::Less, true),
+            (">", Ordering::Greater, false),
+            ("<", Ordering::Less, false),
+            ("=", Ordering::Equal, true),
+        ]
+        .iter()
+        .find_map(|(op, ordering, or_equal)| rest.strip_prefix(op).map(|version| (*ordering, *or_equal, version)))
+        .unwrap_or((Ordering::Greater, true, rest));
+        requirement.version = Some((ordering, or_equal, parse_version(version.trim()).ok_or_else(invalid)?));
+        Ok(requirement)
+    }
+
+    fn matches(&self, installed: &Installed) -> bool {
+        let channel_ok = self.channel.as_deref().is_none_or(|channel| channel == installed.channel);
+        let version_ok = self.version.is_none_or(|(ordering, or_equal, version)| {
+            let actual = installed.version.cmp(&version);
+            actual == ordering || (or_equal && actual == Ordering::Equal)
+        });
+        channel_ok && version_ok
+    }
+}
+
+fn parse_version(text: &str) -> Option<Version> {
+    let mut parts = text.split('.').map(|part| part.parse::<u32>());
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    parts.next().is_none().then_some((major, minor, patch))
+}
+
+// 评测前检查题目要求的工具链；不满足时返回给学生的修复建议
+pub fn check(exercise: &Exercise) -> Result<(), String> {
+    let Some(text) = &exercise.toolchain else {
+        return Ok(());
+    };
+    let requirement = Requirement::parse(text)?;
+    let installed = installed(requirement.channel.as_deref())?;
+    debug!("rustc {} ({}) for {}", installed.release, installed.channel, exercise.name);
+    if requirement.matches(&installed) {
+        return Ok(());
+    }
+    let channel = requirement.channel.as_deref().unwrap_or("stable");
+    Err(t!(
+        "toolchain.mismatch",
+        name = exercise.name,
+        requirement = text,
+        found = format!("{} ({})", installed.release, installed.channel),
+        fix = match requirement.version {
+            Some((Ordering::Less, _, _)) | Some((Ordering::Equal, _, _)) => t!("toolchain.fix_pin", channel = channel),
+            _ => t!("toolchain.fix_update", channel = channel),
+        }
+    ))
+}
+
+// 题目要求的发布渠道，评测命令通过 RUSTUP_TOOLCHAIN 使用该渠道
+pub fn channel(exercise: &Exercise) -> Option<String> {
+    Requirement::parse(exercise.toolchain.as_deref()?).ok()?.channel
+}
+
+// 查询指定渠道（未指定时为默认工具链）的 rustc 版本，结果按渠道缓存
+fn installed(channel: Option<&str>) -> Result<Installed, String> {
+    static CACHE: OnceLock<Mutex<HashMap<Option<String>, Installed>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    let key = channel.map(str::to_string);
+    if let Some(installed) = cache.lock().unwrap().get(&key) {
+        return Ok(installed.clone());
+    }
+
+    let mut rustc = Command::new("rustc");
+    rustc.arg("-vV");
+    if let Some(channel) = channel {
+        rustc.env("RUSTUP_TOOLCHAIN", channel);
+    }
+    let missing = || match channel {
+        Some(channel) => t!("toolchain.channel_missing", channel = channel),
+        None => t!("toolchain.rustc_missing"),
+    };
+    let output = rustc.output().map_err(|_| missing())?;
+    if !output.status.success() {
+        return Err(missing());
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    let release = text
+        .lines()
+        .find_map(|line| line.strip_prefix("release: "))
+        .ok_or_else(missing)?
+        .trim()
+        .to_string();
+    let (number, pre) = release.split_once('-').unwrap_or((&release, ""));
+    let installed = Installed {
+        version: parse_version(number).ok_or_else(missing)?,
+        channel: match pre {
+            pre if pre.starts_with("nightly") || pre.starts_with("dev") => "nightly",
+            pre if pre.starts_with("beta") => "beta",
+            _ => "stable",
+        },
+        release,
+    };
+    cache.lock().unwrap().insert(key, installed.clone());
+    Ok(installed)
+}
@@ -0,0 +1,608 @@
+use crate::event::{Event, EventSink};
+use crate::i18n::{self, t};
+use crate::logging;
+use crate::{
+    evaluate_exercise, exercise_hint, record_result, Exercise, ExerciseConfig, ExerciseResult, Report, RunPolicy, EXERCISES_DIR,
+};
+use colored::{ColoredString, Colorize};
+use log::info;
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+use termion::event::Key;
+use termion::input::TermRead;
+use termion::raw::IntoRawMode;
+use termion::screen::AlternateScreen;
+use termion::{clear, cursor};
+
+const LEFT_PANE_WIDTH: u16 = 32;
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 12;
+const REDRAW_INTERVAL: Duration = Duration::from_millis(250);
+
+// 界面线程接收的消息：评测事件、按键，以及评测线程的状态变化
+enum Message {
+    Grader(Event),
+    Key(Key),
+    Done { index: usize, result: ExerciseResult },
+    Paused,
+    Idle,
+}
+
+// 界面线程发给评测线程的指令
+enum Job {
+    Run(usize),
+    Continue,
+    Quit,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Pending,
+    Running,
+    Passed,
+    Failed,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Filter {
+    All,
+    Failed,
+    Passed,
+    Pending,
+}
+
+impl Filter {
+    fn next(self) -> Filter {
+        match self {
+            Filter::All => Filter::Failed,
+            Filter::Failed => Filter::Passed,
+            Filter::Passed => Filter::Pending,
+            Filter::Pending => Filter::All,
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Filter::All => t!("dashboard.filter_all"),
+            Filter::Failed => t!("dashboard.filter_failed"),
+            Filter::Passed => t!("dashboard.filter_passed"),
+            Filter::Pending => t!("dashboard.filter_pending"),
+        }
+    }
+
+    fn accepts(self, status: Status) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Failed => status == Status::Failed,
+            Filter::Passed => status == Status::Passed,
+            Filter::Pending => matches!(status, Status::Pending | Status::Running),
+        }
+    }
+}
+
+struct StageLog {
+    stage: &'static str,
+    success: bool,
+    output: String,
+}
+
+struct Entry {
+    exercise: Exercise,
+    level: &'static str,
+    status: Status,
+    result: Option<ExerciseResult>,
+    stages: Vec<StageLog>,
+}
+
+struct Progress {
+    index: usize,
+    stage: &'static str,
+    stage_index: usize,
+    stages: usize,
+}
+
+struct Dashboard {
+    entries: Vec<Entry>,
+    selected: usize,
+    follow: bool,
+    filter: Filter,
+    scroll: usize,
+    show_hint: bool,
+    running: Option<Progress>,
+    paused: bool,
+    status_line: String,
+}
+
+// 转发评测事件到界面线程
+struct Forward(Sender<Message>);
+
+impl EventSink for Forward {
+    fn emit(&mut self, event: Event) {
+        let _ = self.0.send(Message::Grader(event));
+    }
+}
+
+// 全屏评测界面：左侧按难度分组列出所有题目，右侧显示当前进度与所选题目的失败输出
+pub fn run(mode: &str, config: ExerciseConfig, policy: RunPolicy, report: &mut Report) -> io::Result<()> {
+    let mut entries = Vec::new();
+    for (level, exercises) in [("easy", config.easy), ("normal", config.normal), ("hard", config.hard)] {
+        for exercise in exercises {
+            entries.push(Entry {
+                exercise,
+                level,
+                status: Status::Pending,
+                result: None,
+                stages: Vec::new(),
+            });
+        }
+    }
+
+    let (message_tx, message_rx) = mpsc::channel();
+    let (job_tx, job_rx) = mpsc::channel();
+
+    let exercises: Vec<Exercise> = entries.iter().map(|entry| entry.exercise.clone()).collect();
+    let watch = mode == "watch";
+    let worker_tx = message_tx.clone();
+    let worker = thread::spawn(move || grade_queue(exercises, watch, policy, job_rx, worker_tx));
+
+    let key_tx = message_tx;
+    thread::spawn(move || {
+        for key in io::stdin().keys().flatten() {
+            if key_tx.send(Message::Key(key)).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut dashboard = Dashboard {
+        entries,
+        selected: 0,
+        follow: true,
+        filter: Filter::All,
+        scroll: 0,
+        show_hint: false,
+        running: None,
+        paused: false,
+        status_line: String::new(),
+    };
+
+    let result = dashboard.show(&message_rx, &job_tx);
+    logging::restore_console();
+    result?;
+
+    // 等待评测线程结束当前题目，避免遗留测试二进制文件或 target 目录
+    if let Some(progress) = &dashboard.running {
+        info!("{}", t!("dashboard.waiting", name = dashboard.entries[progress.index].exercise.name));
+    }
+    let _ = job_tx.send(Job::Quit);
+    let _ = worker.join();
+
+    for entry in &dashboard.entries {
+        if let Some(result) = &entry.result {
+            record_result(report, result.clone());
+        }
+    }
+    Ok(())
+}
+
+// 评测线程：依次评测队列中的题目，watch 模式下每题结束后等待继续指令；
+// --fail-fast 时有题目未通过即清空队列，之后仍可手动重新评测
+fn grade_queue(exercises: Vec<Exercise>, watch: bool, policy: RunPolicy, jobs: Receiver<Job>, messages: Sender<Message>) {
+    let mut queue: VecDeque<usize> = (0..exercises.len()).collect();
+    let mut paused = false;
+    let mut sink = Forward(messages.clone());
+
+    loop {
+        if paused || queue.is_empty() {
+            let _ = messages.send(if paused { Message::Paused } else { Message::Idle });
+            match jobs.recv() {
+                Ok(Job::Run(index)) => queue.push_front(index),
+                Ok(Job::Continue) => {}
+                Ok(Job::Quit) | Err(_) => return,
+            }
+            paused = false;
+            continue;
+        }
+
+        let index = queue.pop_front().unwrap();
+        let result = evaluate_exercise(&exercises[index], Path::new(EXERCISES_DIR), policy, &mut sink);
+        if policy.fail_fast && !result.result {
+            queue.clear();
+        }
+        let _ = messages.send(Message::Done { index, result });
+
+        while let Ok(job) = jobs.try_recv() {
+            match job {
+                Job::Run(index) => queue.push_front(index),
+                Job::Continue => {}
+                Job::Quit => return,
+            }
+        }
+        paused = watch && !queue.is_empty();
+    }
+}
+
+impl Dashboard {
+    fn show(&mut self, messages: &Receiver<Message>, jobs: &Sender<Job>) -> io::Result<()> {
+        let mut screen = AlternateScreen::from(io::stdout().into_raw_mode()?);
+        write!(screen, "{}", cursor::Hide)?;
+        let result = self.event_loop(&mut screen, messages, jobs);
+        write!(screen, "{}", cursor::Show)?;
+        screen.flush()?;
+        result
+    }
+
+    fn event_loop<W: Write>(&mut self, screen: &mut W, messages: &Receiver<Message>, jobs: &Sender<Job>) -> io::Result<()> {
+        loop {
+            self.draw(screen)?;
+            let message = match messages.recv_timeout(REDRAW_INTERVAL) {
+                Ok(message) => message,
+                Err(mpsc::RecvTimeoutError::Timeout) => continue,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+            };
+            match message {
+                Message::Grader(event) => self.apply(event),
+                Message::Done { index, result } => {
+                    let entry = &mut self.entries[index];
+                    entry.status = if result.result { Status::Passed } else { Status::Failed };
+                    entry.result = Some(result);
+                    self.running = None;
+                }
+                Message::Paused => {
+                    self.paused = true;
+                    self.status_line = t!("dashboard.paused_status");
+                }
+                Message::Idle => {
+                    self.paused = false;
+                    self.status_line = t!("dashboard.idle_status");
+                }
+                Message::Key(key) => {
+                    if !self.handle_key(key, jobs) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    fn apply(&mut self, event: Event) {
+        match event {
+            Event::ExerciseStarted { name, stages, .. } => {
+                if let Some(index) = self.index_of(&name) {
+                    let entry = &mut self.entries[index];
+                    entry.status = Status::Running;
+                    entry.stages.clear();
+                    self.running = Some(Progress {
+                        index,
+                        stage: "",
+                        stage_index: 0,
+                        stages,
+                    });
+                    self.paused = false;
+                    if self.follow {
+                        self.select(index);
+                    }
+                }
+            }
+            Event::StageStarted { name, stage, index } => {
+                let running = self.index_of(&name);
+                if let Some(progress) = self.running.as_mut().filter(|progress| Some(progress.index) == running) {
+                    progress.stage = stage;
+                    progress.stage_index = index;
+                }
+            }
+            Event::StageFinished { name, stage, success, output } => {
+                if let Some(index) = self.index_of(&name) {
+                    self.entries[index].stages.push(StageLog { stage, success, output });
+                }
+                if let Some(progress) = &mut self.running {
+                    progress.stage_index += 1;
+                }
+            }
+            Event::ExerciseFinished { name, verdict, .. } => {
+                self.status_line = format!("{}: {}", name, i18n::verdict(verdict));
+            }
+            Event::TestCase { .. } | Event::Diagnostic { .. } | Event::RunFinished { .. } => {}
+        }
+    }
+
+    // 返回 false 表示退出界面
+    fn handle_key(&mut self, key: Key, jobs: &Sender<Job>) -> bool {
+        match key {
+            Key::Char('q') | Key::Ctrl('c') => return false,
+            Key::Up | Key::Char('k') => self.move_selection(-1),
+            Key::Down | Key::Char('j') => self.move_selection(1),
+            Key::PageUp | Key::Char('u') => self.scroll = self.scroll.saturating_sub(10),
+            Key::PageDown | Key::Char('d') => self.scroll += 10,
+            Key::Char('h') => {
+                self.show_hint = !self.show_hint;
+                self.scroll = 0;
+            }
+            Key::Char('f') => {
+                self.filter = self.filter.next();
+                if let Some(index) = self.visible().first().copied() {
+                    if !self.visible().contains(&self.selected) {
+                        self.select(index);
+                    }
+                }
+            }
+            Key::Char('r') => {
+                let entry = &mut self.entries[self.selected];
+                if entry.status != Status::Running {
+                    entry.status = Status::Pending;
+                    let _ = jobs.send(Job::Run(self.selected));
+                    self.follow = true;
+                }
+            }
+            Key::Char('a') => {
+                for index in (0..self.entries.len()).rev() {
+                    if self.entries[index].status == Status::Failed {
+                        self.entries[index].status = Status::Pending;
+                        let _ = jobs.send(Job::Run(index));
+                    }
+                }
+                self.follow = true;
+            }
+            Key::Char(' ') | Key::Char('\n') | Key::Char('n') if self.paused => {
+                self.paused = false;
+                let _ = jobs.send(Job::Continue);
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.exercise.name == name)
+    }
+
+    fn visible(&self) -> Vec<usize> {
+        (0..self.entries.len())
+            .filter(|&index| self.filter.accepts(self.entries[index].status))
+            .collect()
+    }
+
+    fn select(&mut self, index: usize) {
+        if self.selected != index {
+            self.selected = index;
+            self.scroll = 0;
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let visible = self.visible();
+        if visible.is_empty() {
+            return;
+        }
+        let position = visible.iter().position(|&index| index == self.selected).unwrap_or(0);
+        let target = (position as isize + delta).clamp(0, visible.len() as isize - 1) as usize;
+        self.follow = false;
+        self.select(visible[target]);
+    }
+
+    fn draw<W: Write>(&mut self, screen: &mut W) -> io::Result<()> {
+        let (width, height) = termion::terminal_size().unwrap_or((80, 24));
+        if width < MIN_WIDTH || height < MIN_HEIGHT {
+            write!(screen, "{}{}{}", clear::All, cursor::Goto(1, 1), t!("dashboard.too_small"))?;
+            return screen.flush();
+        }
+        let left = LEFT_PANE_WIDTH.min(width / 3).max(12);
+        let right_x = left + 3;
+        let right_width = width.saturating_sub(right_x) as usize;
+        let body_top = 3;
+        let body_bottom = height.saturating_sub(2);
+
+        write!(screen, "{}", clear::All)?;
+
+        let results: Vec<&ExerciseResult> = self.entries.iter().filter_map(|entry| entry.result.as_ref()).collect();
+        let graded = results.len();
+        let passed = results.iter().filter(|result| result.result).count();
+        let score: i32 = results.iter().map(|result| result.score).sum();
+        let max_score: i32 = self.entries.iter().map(|entry| entry.exercise.score).sum();
+        let header = format!(
+            " cargotest  {}",
+            t!(
+                "dashboard.header",
+                graded = graded,
+                total = self.entries.len(),
+                passed = passed,
+                score = score,
+                max = max_score
+            )
+        );
+        write!(screen, "{}{}", cursor::Goto(1, 1), fit(&header, width as usize).bold().reversed())?;
+
+        // 左侧：按难度分组的题目列表
+        let mut lines: Vec<(Option<usize>, String)> = Vec::new();
+        let mut current_level = "";
+        for index in self.visible() {
+            let entry = &self.entries[index];
+            if entry.level != current_level {
+                current_level = entry.level;
+                lines.push((None, t!(&format!("level.{}", current_level)).to_uppercase()));
+            }
+            lines.push((Some(index), format!(" {} {}", icon(entry.status), entry.exercise.name)));
+        }
+        let list_height = body_bottom.saturating_sub(body_top) as usize + 1;
+        let selected_line = lines.iter().position(|(index, _)| *index == Some(self.selected)).unwrap_or(0);
+        let first = (selected_line + 1).saturating_sub(list_height);
+        for (row, (index, text)) in lines.iter().skip(first).take(list_height).enumerate() {
+            let text = fit(text, left as usize);
+            let styled = match index {
+                None => text.bold(),
+                Some(index) => {
+                    let styled = paint(self.entries[*index].status, text);
+                    if *index == self.selected {
+                        styled.reversed()
+                    } else {
+                        styled
+                    }
+                }
+            };
+            write!(screen, "{}{}", cursor::Goto(1, body_top + row as u16), styled)?;
+        }
+
+        // 右侧：运行进度
+        let bar_width = right_width.saturating_sub(16).max(4);
+        let running = match &self.running {
+            Some(progress) => t!(
+                "dashboard.running",
+                name = self.entries[progress.index].exercise.name,
+                stage = i18n::stage(progress.stage),
+                current = (progress.stage_index + 1).min(progress.stages),
+                total = progress.stages
+            ),
+            None if self.paused => t!("dashboard.paused"),
+            None => t!("dashboard.idle"),
+        };
+        write!(screen, "{}{}", cursor::Goto(right_x, body_top), fit(&running, right_width).yellow())?;
+        let (done, total) = match &self.running {
+            Some(progress) => (progress.stage_index, progress.stages),
+            None => (0, 0),
+        };
+        write!(
+            screen,
+            "{}{} {}",
+            cursor::Goto(right_x, body_top + 1),
+            pad(&t!("dashboard.stage_bar"), 8),
+            bar(done, total, bar_width)
+        )?;
+        write!(
+            screen,
+            "{}{} {}",
+            cursor::Goto(right_x, body_top + 2),
+            pad(&t!("dashboard.overall_bar"), 8),
+            bar(graded, self.entries.len(), bar_width)
+        )?;
+
+        // 右侧：所选题目的失败输出或提示
+        let entry = &self.entries[self.selected];
+        let title = if self.show_hint {
+            format!("── {} ", t!("dashboard.hint_title", name = entry.exercise.name))
+        } else {
+            format!("── {} ", t!("dashboard.output_title", name = entry.exercise.name))
+        };
+        write!(screen, "{}{}", cursor::Goto(right_x, body_top + 4), fit(&pad_rule(&title, right_width), right_width).bold())?;
+
+        let mut pane = Vec::new();
+        if self.show_hint {
+            if let Some(title) = &entry.exercise.title {
+                pane.extend(wrap(title.get(), right_width));
+                pane.push(String::new());
+            }
+            let hint = exercise_hint(&entry.exercise).unwrap_or_else(|| t!("dashboard.no_hint"));
+            pane.extend(wrap(&hint, right_width));
+        } else if entry.stages.is_empty() {
+            pane.push(match entry.status {
+                Status::Running => t!("dashboard.grading"),
+                _ => t!("dashboard.not_graded"),
+            });
+        } else {
+            for log in &entry.stages {
+                let outcome = if log.success { t!("dashboard.stage_ok") } else { t!("dashboard.stage_failed") };
+                pane.push(format!("[{}] {}", i18n::stage(log.stage), outcome));
+                if !log.success {
+                    pane.extend(wrap(&log.output, right_width));
+                }
+            }
+        }
+        let pane_top = body_top + 5;
+        let pane_height = body_bottom.saturating_sub(pane_top) as usize + 1;
+        self.scroll = self.scroll.min(pane.len().saturating_sub(pane_height));
+        for (row, line) in pane.iter().skip(self.scroll).take(pane_height).enumerate() {
+            write!(screen, "{}{}", cursor::Goto(right_x, pane_top + row as u16), line)?;
+        }
+
+        // 底部：状态行与快捷键说明
+        write!(screen, "{}{}", cursor::Goto(1, height - 1), fit(&self.status_line, width as usize).dimmed())?;
+        let mut keys = format!(" {}", t!("dashboard.keys", filter = self.filter.label()));
+        if self.paused {
+            keys.push_str(&format!("  {}", t!("dashboard.keys_next")));
+        }
+        write!(screen, "{}{}", cursor::Goto(1, height), fit(&keys, width as usize).reversed())?;
+
+        screen.flush()
+    }
+}
+
+fn icon(status: Status) -> char {
+    match status {
+        Status::Pending => '·',
+        Status::Running => '▶',
+        Status::Passed => '✔',
+        Status::Failed => '✘',
+    }
+}
+
+fn paint(status: Status, text: String) -> ColoredString {
+    match status {
+        Status::Pending => text.normal(),
+        Status::Running => text.yellow(),
+        Status::Passed => text.green(),
+        Status::Failed => text.red(),
+    }
+}
+
+fn bar(done: usize, total: usize, width: usize) -> String {
+    let done = done.min(total);
+    let filled = (done * width).checked_div(total).unwrap_or(0);
+    let percent = (done * 100).checked_div(total).unwrap_or(0);
+    format!("[{}{}] {:>3}%", "#".repeat(filled), "-".repeat(width - filled), percent)
+}
+
+fn pad_rule(title: &str, width: usize) -> String {
+    format!("{}{}", title, "─".repeat(width.saturating_sub(text_width(title))))
+}
+
+fn pad(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(text_width(text))))
+}
+
+// 终端中的显示宽度：中日韩文字与全角符号占两列
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x1100..=0x115F | 0x2E80..=0xA4CF | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF | 0xFE30..=0xFE4F | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6 => 2,
+        _ => 1,
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+// 截断到指定显示宽度
+fn fit(text: &str, width: usize) -> String {
+    let mut used = 0;
+    text.chars()
+        .take_while(|&c| {
+            used += char_width(c);
+            used <= width
+        })
+        .collect()
+}
+
+// 按显示宽度折行，同时展开制表符
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(2);
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        let line = line.replace('\t', "    ");
+        let mut current = String::new();
+        let mut used = 0;
+        for c in line.chars() {
+            if used + char_width(c) > width {
+                lines.push(std::mem::take(&mut current));
+                used = 0;
+            }
+            used += char_width(c);
+            current.push(c);
+        }
+        lines.push(current);
+    }
+    lines
+}
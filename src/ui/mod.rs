@@ -0,0 +1,9 @@
+pub mod dashboard;
+
+use std::env;
+use std::io;
+
+// 仅当标准输入输出都连接终端且未设置 NO_COLOR 时启用全屏界面，否则退化为纯文本输出
+pub fn dashboard_enabled() -> bool {
+    env::var_os("NO_COLOR").is_none() && termion::is_tty(&io::stdout()) && termion::is_tty(&io::stdin())
+}
@@ -0,0 +1,169 @@
+use crate::cli::Args;
+use crate::event::Silent;
+use crate::i18n::t;
+use crate::{evaluate_exercise_at, exercise_path, load_exercise_config, Exercise};
+use log::{error, info, warn};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_SOLUTIONS_DIR: &str = "solutions";
+
+// 单文件题目中官方测试模块的起始标记
+const TEST_MARKER: &str = "#[cfg(test)]";
+
+struct Verification {
+    name: String,
+    stub_passed: bool,
+    reference_passed: Option<bool>,
+}
+
+impl Verification {
+    fn problem(&self) -> Option<&'static str> {
+        match (self.reference_passed, self.stub_passed) {
+            (Some(false), _) => Some("verify.reference_fails"),
+            (_, true) => Some("verify.stub_passes"),
+            _ => None,
+        }
+    }
+}
+
+// verify 命令：用 solutions/ 中的参考答案评测每道题的官方测试，并确认未作答的题目无法通过
+pub fn verify(mut args: Args) -> Result<(), String> {
+    let solutions = PathBuf::from(args.value("--solutions")?.unwrap_or_else(|| DEFAULT_SOLUTIONS_DIR.to_string()));
+    args.finish()?;
+
+    let config = load_exercise_config("exercise_config.json").map_err(|e| t!("config.load_failed", error = e))?;
+    let workspace = std::env::temp_dir().join(format!("cargotest-verify-{}", std::process::id()));
+    let result = verify_all(&[config.easy, config.normal, config.hard].concat(), &solutions, &workspace);
+    if let Err(e) = fs::remove_dir_all(&workspace) {
+        warn!("{}", t!("verify.remove_failed", path = workspace.display(), error = e));
+    }
+    let results = result?;
+
+    info!("\n{}", t!("verify.title"));
+    let mut problems = 0;
+    for verification in &results {
+        let reference = match verification.reference_passed {
+            Some(true) => t!("verify.reference_passed"),
+            Some(false) => t!("verify.reference_failed"),
+            None => t!("verify.reference_missing"),
+        };
+        let stub = if verification.stub_passed { t!("verify.stub_passed") } else { t!("verify.stub_failed") };
+        let line = format!("{:<16} {:<18} {:<14}", verification.name, reference, stub);
+        match verification.problem() {
+            Some(problem) => {
+                problems += 1;
+                error!("{} {}", line, t!(problem));
+            }
+            None => info!("{} {}", line, t!("verify.ok")),
+        }
+    }
+    let missing = results.iter().filter(|verification| verification.reference_passed.is_none()).count();
+    if missing > 0 {
+        warn!("{}", t!("verify.missing_solutions", count = missing, path = solutions.display()));
+    }
+
+    if problems > 0 {
+        return Err(t!("verify.failed", problems = problems, total = results.len()));
+    }
+    info!("{}", t!("verify.passed", total = results.len()));
+    Ok(())
+}
+
+fn verify_all(exercises: &[Exercise], solutions: &Path, workspace: &Path) -> Result<Vec<Verification>, String> {
+    let mut results = Vec::new();
+    for exercise in exercises {
+        // 在临时目录中评测未作答的题目副本，避免改动 exercises/
+        let stub_path = workspace.join("stub").join(&exercise.path);
+        copy_path(&exercise_path(exercise), &stub_path)
+            .map_err(|e| t!("verify.copy_failed", name = exercise.name, error = e))?;
+        let stub_passed = evaluate_exercise_at(exercise, stub_path, &mut Silent).result;
+
+        let reference = solutions.join(&exercise.path);
+        let reference_passed = if reference.exists() {
+            let reference_path = workspace.join("reference").join(&exercise.path);
+            prepare_reference(exercise, &reference, &reference_path)?;
+            Some(evaluate_exercise_at(exercise, reference_path, &mut Silent).result)
+        } else {
+            None
+        };
+
+        results.push(Verification {
+            name: exercise.name.clone(),
+            stub_passed,
+            reference_passed,
+        });
+    }
+    Ok(results)
+}
+
+// 把参考答案叠加到题目副本上，官方测试始终保留题目中的版本
+fn prepare_reference(exercise: &Exercise, reference: &Path, target: &Path) -> Result<(), String> {
+    let original = exercise_path(exercise);
+    copy_path(&original, target).map_err(|e| t!("verify.copy_failed", name = exercise.name, error = e))?;
+
+    if reference.is_file() {
+        // 单文件题目：参考答案的实现部分 + 题目中的测试模块
+        let official = fs::read_to_string(&original).map_err(|e| format!("{}: {}", original.display(), e))?;
+        let solution = fs::read_to_string(reference).map_err(|e| format!("{}: {}", reference.display(), e))?;
+        let spliced = splice_tests(&solution, &official)
+            .ok_or_else(|| t!("verify.no_test_module", path = original.display(), marker = TEST_MARKER))?;
+        return fs::write(target, spliced).map_err(|e| format!("{}: {}", target.display(), e));
+    }
+
+    overlay(reference, target, Path::new("")).map_err(|e| t!("verify.apply_failed", path = reference.display(), error = e))
+}
+
+fn splice_tests(solution: &str, official: &str) -> Option<String> {
+    let tests = &official[official.find(TEST_MARKER)?..];
+    let body = match solution.find(TEST_MARKER) {
+        Some(index) => &solution[..index],
+        None => solution,
+    };
+    Some(format!("{}{}", body, tests))
+}
+
+// 测试文件（src/tests.rs 与 tests/ 目录）属于官方测试，不会被参考答案覆盖
+fn is_official_test(relative: &Path) -> bool {
+    relative == Path::new("src/tests.rs") || relative.starts_with("tests")
+}
+
+fn overlay(source: &Path, target: &Path, relative: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(source.join(relative))? {
+        let entry = entry?;
+        let relative = relative.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            overlay(source, target, &relative)?;
+        } else if is_official_test(&relative) {
+            warn!("{}", t!("verify.ignoring_test", path = source.join(&relative).display()));
+        } else {
+            let destination = target.join(&relative);
+            if let Some(parent) = destination.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(entry.path(), destination)?;
+        }
+    }
+    Ok(())
+}
+
+// 复制文件或目录，跳过构建产物 target/
+fn copy_path(source: &Path, target: &Path) -> io::Result<()> {
+    if source.is_file() {
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(source, target)?;
+        return Ok(());
+    }
+    fs::create_dir_all(target)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        if entry.file_name() == "target" {
+            continue;
+        }
+        copy_path(&entry.path(), &target.join(entry.file_name()))?;
+    }
+    Ok(())
+}
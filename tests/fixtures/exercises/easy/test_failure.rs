@@ -0,0 +1,13 @@
+fn add_one(x: i32) -> i32 {
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_one() {
+        assert_eq!(add_one(1), 2);
+    }
+}
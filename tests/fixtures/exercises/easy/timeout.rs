@@ -0,0 +1,18 @@
+fn add_one(x: i32) -> i32 {
+    loop {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        if x < 0 {
+            return x + 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_one() {
+        assert_eq!(add_one(1), 2);
+    }
+}
@@ -0,0 +1,15 @@
+#![deny(clippy::needless_return)]
+
+pub fn add_one(x: i32) -> i32 {
+    return x + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_one() {
+        assert_eq!(add_one(1), 2);
+    }
+}
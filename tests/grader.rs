@@ -0,0 +1,229 @@
+use cargotest::event::{Event, EventSink};
+use cargotest::{
+    evaluate_config, evaluate_exercise, load_exercise_config, save_report_to_json, Exercise, ExerciseConfig, ExerciseResult,
+    Report, RunPolicy,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+// 收集评测事件，便于检查各阶段的结果
+#[derive(Default)]
+struct Recorder(Vec<Event>);
+
+impl EventSink for Recorder {
+    fn emit(&mut self, event: Event) {
+        self.0.push(event);
+    }
+}
+
+impl Recorder {
+    fn verdicts(&self) -> Vec<&'static str> {
+        self.0
+            .iter()
+            .filter_map(|event| match event {
+                Event::ExerciseFinished { verdict, .. } => Some(*verdict),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn stage(&self, name: &str) -> Option<bool> {
+        self.0.iter().find_map(|event| match event {
+            Event::StageFinished { stage, success, .. } if *stage == name => Some(*success),
+            _ => None,
+        })
+    }
+}
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+fn config() -> ExerciseConfig {
+    load_exercise_config(fixtures().join("exercise_config.json").to_str().unwrap()).unwrap()
+}
+
+// 每个测试在独立的临时目录中评测题目副本，避免并行测试共用测试二进制文件和 target 目录
+fn workspace(test: &str, config: &ExerciseConfig) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("cargotest-{}-{}", test, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for exercise in config.exercises() {
+        copy(&fixtures().join("exercises").join(&exercise.path), &root.join(&exercise.path));
+    }
+    root
+}
+
+fn copy(from: &Path, to: &Path) {
+    if from.is_dir() {
+        for entry in fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+            copy(&entry.path(), &to.join(entry.file_name()));
+        }
+    } else {
+        fs::create_dir_all(to.parent().unwrap()).unwrap();
+        fs::copy(from, to).unwrap();
+    }
+}
+
+fn only(names: &[&str]) -> ExerciseConfig {
+    let mut config = config();
+    for exercises in [&mut config.easy, &mut config.normal, &mut config.hard] {
+        exercises.retain(|exercise| names.contains(&exercise.name.as_str()));
+    }
+    config
+}
+
+fn grade(name: &str, policy: RunPolicy) -> (ExerciseResult, Recorder) {
+    let config = only(&[name]);
+    let exercise: &Exercise = config.exercises().next().unwrap();
+    let root = workspace(name, &config);
+    let mut recorder = Recorder::default();
+    let result = evaluate_exercise(exercise, &root, policy, &mut recorder);
+    fs::remove_dir_all(&root).unwrap();
+    (result, recorder)
+}
+
+#[test]
+fn loads_fixture_config() {
+    let config = config();
+    assert_eq!(config.exercises().count(), 7);
+    assert_eq!(config.easy[3].timeout, Some(3));
+    assert!(config.scoring.is_none());
+}
+
+#[test]
+fn passing_single_file() {
+    let (result, events) = grade("passing", RunPolicy::default());
+    assert!(result.result);
+    assert_eq!(result.score, 1);
+    assert_eq!(events.verdicts(), ["TEST PASSED"]);
+    assert_eq!(events.stage("compile"), Some(true));
+    assert_eq!(events.stage("test"), Some(true));
+    assert!(result.attempts.is_empty());
+}
+
+#[test]
+fn compile_error_reports_diagnostics() {
+    let (result, events) = grade("compile_error", RunPolicy::default());
+    assert!(!result.result);
+    assert_eq!(result.score, 0);
+    assert_eq!(events.verdicts(), ["COMPILATION FAILED"]);
+    assert_eq!(events.stage("test"), None);
+    let spans: Vec<(String, usize)> = events
+        .0
+        .iter()
+        .filter_map(|event| match event {
+            Event::Diagnostic { diagnostic, .. } if diagnostic.level == "error" => Some(&diagnostic.spans),
+            _ => None,
+        })
+        .flatten()
+        .filter(|span| span.primary)
+        .map(|span| (span.file.clone(), span.line_start))
+        .collect();
+    assert!(spans.iter().any(|(file, line)| file.ends_with("compile_error.rs") && *line == 2), "{:?}", spans);
+}
+
+#[test]
+fn test_failure_reports_failed_cases() {
+    let (result, events) = grade("test_failure", RunPolicy::default());
+    assert!(!result.result);
+    assert_eq!(events.verdicts(), ["TEST FAILED"]);
+    assert_eq!(events.stage("compile"), Some(true));
+    assert_eq!(events.stage("test"), Some(false));
+    assert!(events.0.iter().any(|event| matches!(
+        event,
+        Event::TestCase { case, .. } if case.test == "tests::adds_one" && case.outcome == "failed"
+    )));
+}
+
+#[test]
+fn passing_cargo_project() {
+    let (result, events) = grade("passing_project", RunPolicy::default());
+    assert!(result.result);
+    assert_eq!(result.score, 6);
+    assert_eq!(events.verdicts(), ["PASSED"]);
+}
+
+#[test]
+fn clippy_failure_fails_project() {
+    let (result, events) = grade("clippy_failure", RunPolicy::default());
+    assert!(!result.result);
+    assert_eq!(events.verdicts(), ["FAILED"]);
+    assert_eq!(events.stage("build"), Some(true));
+    assert_eq!(events.stage("test"), Some(true));
+    assert_eq!(events.stage("clippy"), Some(false));
+}
+
+#[test]
+fn timeout_kills_test() {
+    let started = Instant::now();
+    let (result, events) = grade("timeout", RunPolicy::default());
+    assert!(!result.result);
+    assert_eq!(events.verdicts(), ["TIMED OUT"]);
+    assert_eq!(events.stage("test"), Some(false));
+    assert!(started.elapsed() < Duration::from_secs(30));
+}
+
+#[test]
+fn unknown_type_fails() {
+    let (result, events) = grade("unknown_type", RunPolicy::default());
+    assert!(!result.result);
+    assert!(events.verdicts().is_empty());
+}
+
+#[test]
+fn retries_record_each_attempt() {
+    let policy = RunPolicy {
+        retries: 2,
+        ..RunPolicy::default()
+    };
+    let (result, events) = grade("test_failure", policy);
+    assert!(!result.result);
+    assert_eq!(result.attempts.len(), 3);
+    assert!(result.attempts.iter().all(|attempt| !attempt.passed));
+    assert_eq!(result.attempts[0].verdict.as_deref(), Some("TEST FAILED"));
+    assert_eq!(events.verdicts().len(), 3);
+
+    let (result, _) = grade("passing", policy);
+    assert_eq!(result.attempts.len(), 1);
+    assert!(result.attempts[0].passed);
+}
+
+#[test]
+fn evaluates_config_into_report() {
+    let config = only(&["passing", "test_failure", "compile_error"]);
+    let root = workspace("report", &config);
+    let report = evaluate_config(&config, &root, RunPolicy::default(), &mut Recorder::default());
+
+    let names: Vec<&str> = report.exercises.iter().map(|result| result.name.as_str()).collect();
+    assert_eq!(names, ["passing", "compile_error", "test_failure"]);
+    assert_eq!(report.statistics.total_exercises, 3);
+    assert_eq!(report.statistics.total_successes, 1);
+    assert_eq!(report.statistics.total_failures, 2);
+    assert_eq!(report.statistics.total_score, 1);
+    assert_eq!(report.statistics.max_score, 3);
+
+    let path = root.join("report.json");
+    save_report_to_json(path.to_str().unwrap(), &report).unwrap();
+    let saved: Report = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(saved.statistics.total_score, 1);
+    assert_eq!(saved.exercises.len(), 3);
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn fail_fast_stops_at_first_failure() {
+    let config = only(&["test_failure", "passing"]);
+    let root = workspace("fail-fast", &config);
+    let policy = RunPolicy {
+        fail_fast: true,
+        ..RunPolicy::default()
+    };
+    let mut recorder = Recorder::default();
+    let report = evaluate_config(&config, &root, policy, &mut recorder);
+    assert_eq!(report.exercises.len(), 1);
+    assert_eq!(report.statistics.total_failures, 1);
+    assert_eq!(recorder.verdicts(), ["TEST FAILED"]);
+    fs::remove_dir_all(&root).unwrap();
+}